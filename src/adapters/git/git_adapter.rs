@@ -1,9 +1,12 @@
 use std::path::{Path, PathBuf};
 
-use crate::domain::{
-    adapters::{self, CheckoutStatus, CommitMsgStatus, GitResult, GitSystem},
-    errors::GitError,
-    models::path::{AbsolutePath, PathType},
+use crate::{
+    domain::{
+        adapters::{self, CheckoutStatus, CommitMsgStatus, GitResult, GitSystem},
+        errors::GitError,
+        models::path::{AbsolutePath, PathType},
+    },
+    utils::string::OptionStr,
 };
 
 pub struct Git<S: GitSystem> {
@@ -36,7 +39,7 @@ impl<S: GitSystem> adapters::Git for Git<S> {
             GitError::RootDirectory
         })?;
 
-        let repo = repo_dir.split('/').last().ok_or_else(|| {
+        let repo = repo_dir.split('/').next_back().ok_or_else(|| {
             log::error!("Failed to get repository name");
             GitError::RootDirectory
         })?;
@@ -61,11 +64,80 @@ impl<S: GitSystem> adapters::Git for Git<S> {
         Ok(branch)
     }
 
+    fn branches(&self) -> Result<Vec<String>, GitError> {
+        let branches = self
+            .git
+            .command(&["for-each-ref", "--format=%(refname:short)", "refs/heads"])
+            .get_output()
+            .map_err(|e| {
+                log::error!("Failed to get local branches: {}", e);
+                GitError::Branches
+            })?;
+
+        log::info!("local git branches '{}'", branches.trim());
+
+        Ok(branches
+            .lines()
+            .map(|branch| branch.trim().to_string())
+            .filter(|branch| !branch.is_empty())
+            .collect())
+    }
+
+    fn config_value(&self, key: &str) -> Result<Option<String>, GitError> {
+        let value = self
+            .git
+            .command(&["config", "--get", key])
+            .get_output()
+            .map_err(|e| {
+                log::error!("Failed to get config value '{}': {}", key, e);
+                GitError::ConfigValue { key: key.into() }
+            })?;
+
+        log::info!("git config value '{}' = '{}'", key, value.trim());
+
+        Ok(value.trim().to_string().none_if_empty())
+    }
+
+    fn short_sha(&self, revision: &str) -> Result<Option<String>, GitError> {
+        let sha = self
+            .git
+            .command(&["rev-parse", "--short", "--verify", "--quiet", revision])
+            .get_output()
+            .map_err(|e| {
+                log::error!("Failed to get short sha for '{}': {}", revision, e);
+                GitError::ShortSha {
+                    revision: revision.into(),
+                }
+            })?;
+
+        log::info!("git short sha for '{}' = '{}'", revision, sha.trim());
+
+        Ok(sha.trim().to_string().none_if_empty())
+    }
+
+    fn is_dirty(&self) -> Result<bool, GitError> {
+        let status = self
+            .git
+            .command(&["status", "--porcelain"])
+            .get_output()
+            .map_err(|e| {
+                log::error!("Failed to get working tree status: {}", e);
+                GitError::Status
+            })?;
+
+        log::info!("git working tree status '{}'", status.trim());
+
+        Ok(!status.trim().is_empty())
+    }
+
     fn checkout(&self, name: &str, status: CheckoutStatus) -> Result<(), GitError> {
         log::info!("checkout '{:?}' branch", status);
 
-        let mut command = match status {
-            CheckoutStatus::New => self.git.command(&["checkout", "-b", name]),
+        let mut command = match &status {
+            CheckoutStatus::New { from: None } => self.git.command(&["checkout", "-b", name]),
+            CheckoutStatus::New { from: Some(from) } => {
+                self.git.command(&["checkout", "-b", name, from])
+            }
             CheckoutStatus::Existing => self.git.command(&["checkout", name]),
         };
 
@@ -224,6 +296,89 @@ mod tests {
         assert!(matches!(err, GitError::BranchName));
     }
 
+    #[test]
+    fn config_value_adds_expected_git_options() {
+        let git = Git {
+            git: GitSystemMock {
+                result: |args| {
+                    assert_eq!(args, ["config", "--get", "user.name"]);
+
+                    GitResultMock {
+                        get_status_result: || panic!("Should not be called!"),
+                        get_output_result: || Ok("Jane Doe\n".into()),
+                    }
+                },
+            },
+        };
+
+        let value = git.config_value("user.name").unwrap();
+        assert_eq!(value, Some("Jane Doe".into()));
+    }
+
+    #[test]
+    fn config_value_is_none_when_not_set() {
+        let git = Git {
+            git: GitSystemMock {
+                result: |_| GitResultMock {
+                    get_status_result: || panic!("Should not be called!"),
+                    get_output_result: || Ok("".into()),
+                },
+            },
+        };
+
+        assert_eq!(git.config_value("user.name").unwrap(), None);
+    }
+
+    #[test]
+    fn config_value_errors_on_get_output_system_command() {
+        let git = Git {
+            git: GitSystemMock {
+                result: |_| GitResultMock {
+                    get_status_result: || panic!("Should not be called!"),
+                    get_output_result: || anyhow::bail!("Whoops"),
+                },
+            },
+        };
+
+        let err = git.config_value("user.name").unwrap_err();
+        assert!(matches!(err, GitError::ConfigValue { key } if key == "user.name"));
+    }
+
+    #[test]
+    fn short_sha_adds_expected_git_options() {
+        let git = Git {
+            git: GitSystemMock {
+                result: |args| {
+                    assert_eq!(
+                        args,
+                        ["rev-parse", "--short", "--verify", "--quiet", "HEAD"]
+                    );
+
+                    GitResultMock {
+                        get_status_result: || panic!("Should not be called!"),
+                        get_output_result: || Ok("a1b2c3d\n".into()),
+                    }
+                },
+            },
+        };
+
+        assert_eq!(git.short_sha("HEAD").unwrap(), Some("a1b2c3d".into()));
+    }
+
+    #[test]
+    fn short_sha_is_none_without_any_commits() {
+        let git = Git {
+            git: GitSystemMock {
+                result: |_| GitResultMock {
+                    get_status_result: || panic!("Should not be called!"),
+                    get_output_result: || Ok("".into()),
+                },
+            },
+        };
+
+        assert_eq!(git.short_sha("HEAD").unwrap(), None);
+    }
+
     #[test]
     fn commit_with_template_with_incomplete_status_adds_expected_options() {
         let path = valid_file_path();
@@ -331,7 +486,97 @@ mod tests {
             },
         };
 
-        git.checkout("my-branch", CheckoutStatus::New).unwrap()
+        git.checkout("my-branch", CheckoutStatus::New { from: None })
+            .unwrap()
+    }
+
+    #[test]
+    fn checkout_new_branch_from_ref_adds_expected_git_options() {
+        let git = Git {
+            git: GitSystemMock {
+                result: |args| {
+                    assert_eq!(args, ["checkout", "-b", "my-branch", "origin/main"]);
+                    GitResultMock {
+                        get_status_result: || Ok(()),
+                        get_output_result: || panic!("Should not be called!"),
+                    }
+                },
+            },
+        };
+
+        git.checkout(
+            "my-branch",
+            CheckoutStatus::New {
+                from: Some("origin/main".into()),
+            },
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn branches_adds_expected_git_options() {
+        let git = Git {
+            git: GitSystemMock {
+                result: |args| {
+                    assert_eq!(
+                        args,
+                        ["for-each-ref", "--format=%(refname:short)", "refs/heads"]
+                    );
+                    GitResultMock {
+                        get_status_result: || panic!("Should not be called!"),
+                        get_output_result: || Ok("main\nfeature/login\n\n".into()),
+                    }
+                },
+            },
+        };
+
+        assert_eq!(git.branches().unwrap(), vec!["main", "feature/login"]);
+    }
+
+    #[test]
+    fn branches_errors_on_get_output_system_command() {
+        let git = Git {
+            git: GitSystemMock {
+                result: |_| GitResultMock {
+                    get_status_result: || panic!("Should not be called!"),
+                    get_output_result: || anyhow::bail!("Whoops"),
+                },
+            },
+        };
+
+        let err = git.branches().unwrap_err();
+        assert!(matches!(err, GitError::Branches));
+    }
+
+    #[test]
+    fn is_dirty_adds_expected_git_options() {
+        let git = Git {
+            git: GitSystemMock {
+                result: |args| {
+                    assert_eq!(args, ["status", "--porcelain"]);
+                    GitResultMock {
+                        get_status_result: || panic!("Should not be called!"),
+                        get_output_result: || Ok(" M src/main.rs\n".into()),
+                    }
+                },
+            },
+        };
+
+        assert!(git.is_dirty().unwrap());
+    }
+
+    #[test]
+    fn is_dirty_with_clean_working_tree_is_false() {
+        let git = Git {
+            git: GitSystemMock {
+                result: |_| GitResultMock {
+                    get_status_result: || panic!("Should not be called!"),
+                    get_output_result: || Ok("\n".into()),
+                },
+            },
+        };
+
+        assert!(!git.is_dirty().unwrap());
     }
 
     #[test]
@@ -362,7 +607,9 @@ mod tests {
             },
         };
 
-        let err = git.checkout("name", CheckoutStatus::New).unwrap_err();
+        let err = git
+            .checkout("name", CheckoutStatus::New { from: None })
+            .unwrap_err();
         assert!(matches!(err, GitError::Checkout { name } if name == "name"));
     }
 
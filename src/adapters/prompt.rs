@@ -2,7 +2,7 @@ use std::fmt::Display;
 
 use crate::{
     domain::{
-        adapters::prompt::{Prompter, SelectItem},
+        adapters::prompt::{Prompter, SelectItem, TextPrompt},
         errors::UserInputError,
     },
     utils::string::OptionStr,
@@ -10,11 +10,16 @@ use crate::{
 use colored::Colorize;
 use inquire::{
     ui::{Attributes, Color, RenderConfig, StyleSheet, Styled},
+    validator::Validation,
     InquireError, Select, Text,
 };
 
 impl<T> Display for SelectItem<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(group) = &self.group {
+            write!(f, "{} › ", group.dimmed())?;
+        }
+
         write!(
             f,
             "{} {}",
@@ -78,6 +83,37 @@ impl Prompter for Prompt {
 
         Ok(result.none_if_empty())
     }
+
+    fn text_prompt(&self, prompt: TextPrompt) -> Result<Option<String>, UserInputError> {
+        let question = format!("{}:", prompt.question);
+        let default = prompt.default.clone().unwrap_or_default();
+
+        let mut text = Text::new(&question)
+            .with_default(&default)
+            .with_render_config(Self::get_render_config());
+
+        if let Some(help) = &prompt.help {
+            text = text.with_help_message(help);
+        }
+
+        if let Some(pattern) = prompt.pattern.clone() {
+            text = text.with_validator(move |input: &str| {
+                if input.is_empty() || pattern.is_match(input) {
+                    Ok(Validation::Valid)
+                } else {
+                    Ok(Validation::Invalid(
+                        format!("Expected to match the pattern '{pattern}'").into(),
+                    ))
+                }
+            });
+        }
+
+        let result = text
+            .prompt_skippable()
+            .map_err(|e| into_domain_error(&prompt.question, e))?;
+
+        Ok(result.none_if_empty())
+    }
 }
 
 fn into_domain_error(name: &str, error: InquireError) -> UserInputError {
@@ -107,10 +143,11 @@ mod tests {
             name: Faker.fake(),
             description: Faker.fake(),
             value: "value",
+            group: None,
         };
 
-        assert_eq!(false, Prompt::filter("invalid", &item));
-        assert_eq!(false, Prompt::filter("valui", &item));
+        assert!(!Prompt::filter("invalid", &item));
+        assert!(!Prompt::filter("valui", &item));
     }
 
     #[test]
@@ -119,10 +156,11 @@ mod tests {
             name: Faker.fake(),
             description: Faker.fake(),
             value: "VALUE",
+            group: None,
         };
 
-        assert_eq!(false, Prompt::filter("VALUE", &item));
-        assert_eq!(false, Prompt::filter("value", &item));
-        assert_eq!(false, Prompt::filter("Contains value in the string", &item));
+        assert!(!Prompt::filter("VALUE", &item));
+        assert!(!Prompt::filter("value", &item));
+        assert!(!Prompt::filter("Contains value in the string", &item));
     }
 }
@@ -1,3 +1,5 @@
+use std::collections::BTreeMap;
+
 use chrono::{DateTime, Utc};
 use rusqlite::{types::Type, Row};
 
@@ -106,12 +108,41 @@ impl<'a> TryFrom<&Row<'a>> for Branch {
         let branch = Branch {
             name: value.get(0)?,
             ticket: value.get(1)?,
-            data: value.get(2)?,
+            variables: branch_variables(value.get(2)?)?,
             created,
             link: value.get(4)?,
             scope: value.get(5)?,
+            repo: value.get(6)?,
         };
 
         Ok(branch)
     }
 }
+
+/// User defined variables are stored as yaml key value pairs within the branch `data` column.
+pub fn branch_data(branch: &Branch) -> Result<Option<Vec<u8>>, PersistError> {
+    if branch.variables.is_empty() {
+        return Ok(None);
+    }
+
+    let data = serde_yaml::to_string(&branch.variables).map_err(|e| {
+        log::error!("Failed to serialize branch variables, {}", e);
+        PersistError::Validation {
+            name: "branch".into(),
+            source: e.into(),
+        }
+    })?;
+
+    Ok(Some(data.into_bytes()))
+}
+
+fn branch_variables(data: Option<Vec<u8>>) -> Result<BTreeMap<String, String>, rusqlite::Error> {
+    let Some(data) = data else {
+        return Ok(BTreeMap::new());
+    };
+
+    serde_yaml::from_slice(&data).map_err(|e| {
+        log::error!("Corrupted data failed to convert branch variables, {}", e);
+        rusqlite::Error::FromSqlConversionFailure(2, Type::Blob, e.into())
+    })
+}
@@ -21,7 +21,7 @@ pub struct MigrationContext {
 pub fn db_migrations(
     connection: &mut Connection,
     context: MigrationContext,
-) -> anyhow::Result<Migrations> {
+) -> anyhow::Result<Migrations<'_>> {
     log::info!("Run migrations for version {}", context.version);
 
     // TODO: Move the migrations into a directory https://github.com/cljoly/rusqlite_migration/blob/08dc155cdedc83a2aef1017e95315fa6ca501daf/examples/from-directory/migrations/01-friend_car/up.sql#L1
@@ -47,6 +47,9 @@ pub fn db_migrations(
             .down("ALTER TABLE branch DROP COLUMN link;"),
         M::up("ALTER TABLE branch ADD COLUMN scope TEXT;")
             .down("ALTER TABLE branch DROP COLUMN scope;"),
+        // Existing branches are backfilled per repo via `backfill_branch_repos` as the repo names aren't known here.
+        M::up("ALTER TABLE branch ADD COLUMN repo TEXT;")
+            .down("ALTER TABLE branch DROP COLUMN repo;"),
     ]);
 
     let current_version: usize = migrations
@@ -72,6 +75,31 @@ pub fn db_migrations(
     Ok(migrations)
 }
 
+/// Backfill the repo of branches stored before it was recorded by splitting their `{repo}-{branch}` name
+/// against the current repo. Names also prefixed by a longer known repo i.e `inkan-cli-main` when within
+/// `inkan` are left for that repo to claim.
+pub fn backfill_branch_repos(connection: &Connection, repo: &str) -> anyhow::Result<()> {
+    let backfilled = connection
+        .execute(
+            "UPDATE branch SET repo = ?1
+            WHERE repo IS NULL AND instr(name, ?1 || '-') = 1
+            AND NOT EXISTS (
+                SELECT 1 FROM branch AS known
+                WHERE length(known.repo) > length(?1)
+                AND instr(known.repo, ?1 || '-') = 1
+                AND instr(branch.name, known.repo || '-') = 1
+            );",
+            [repo.trim()],
+        )
+        .context("Failed to backfill the repo of stored branches.")?;
+
+    if backfilled > 0 {
+        log::info!("backfilled repo '{}' for {} branches", repo, backfilled);
+    }
+
+    Ok(())
+}
+
 fn migrate_default_configurations(
     default_configs: DefaultConfig,
     connection: &mut Connection,
@@ -136,7 +164,9 @@ mod tests {
     use rusqlite::Connection;
     use std::path::Path;
 
-    use crate::adapters::migrations::{db_migrations, DefaultConfig, MigrationContext};
+    use crate::adapters::migrations::{
+        backfill_branch_repos, db_migrations, DefaultConfig, MigrationContext,
+    };
 
     fn arrange(context: MigrationContext) -> (Connection, Vec<String>, MigrationContext) {
         let mut connection = Connection::open_in_memory().unwrap();
@@ -204,7 +234,7 @@ mod tests {
         // Assert
         let default_configs = get_default_configs(&connection);
         let default_config = default_configs.get(1).unwrap();
-        let conventional_config = default_configs.get(0).unwrap();
+        let conventional_config = default_configs.first().unwrap();
 
         assert_eq!(conventional_config.2, "ACTIVE");
         assert_eq!(default_config.2, "DISABLED");
@@ -240,7 +270,7 @@ mod tests {
 
         assert_eq!(default_configs.len(), 2);
         let default_config = default_configs.get(1).unwrap();
-        let conventional_config = default_configs.get(0).unwrap();
+        let conventional_config = default_configs.first().unwrap();
 
         assert_eq!(default_config.0, "default");
         assert_eq!(default_config.1, "default.yml");
@@ -270,7 +300,7 @@ mod tests {
 
         assert_eq!(default_configs.len(), 2);
         let default_config = default_configs.get(1).unwrap();
-        let conventional_config = default_configs.get(0).unwrap();
+        let conventional_config = default_configs.first().unwrap();
 
         assert_eq!(default_config.0, "default");
         assert_eq!(default_config.1, "default.yml");
@@ -294,7 +324,7 @@ mod tests {
         // Insert custom user 'conventional' config and assure dummy data is there.
         connection.execute("INSERT INTO config (key, path, status) VALUES ('conventional', 'custom_path.yml', 'ACTIVE');", []).unwrap();
         let default_configs = get_default_configs(&connection);
-        let conventional_config = default_configs.get(0).unwrap();
+        let conventional_config = default_configs.first().unwrap();
 
         assert_eq!(conventional_config.0, "conventional");
         assert_eq!(conventional_config.1, "custom_path.yml");
@@ -315,13 +345,108 @@ mod tests {
 
         // Assert
         let default_configs = get_default_configs(&connection);
-        let conventional_config = default_configs.get(0).unwrap();
+        let conventional_config = default_configs.first().unwrap();
 
         assert_eq!(conventional_config.0, "conventional");
         assert_eq!(conventional_config.1, "conventional.yml");
         assert_eq!(conventional_config.2, "DISABLED");
     }
 
+    #[test]
+    fn verify_migration_5_keeps_existing_branches_without_a_repo() {
+        // Arrange
+        let (connection, ..) = arrange(MigrationContext {
+            default_configs: None,
+            version: 4,
+        });
+
+        connection
+            .execute(
+                "INSERT INTO branch (name, ticket, created) VALUES ('inkan-main', 'main', '2023-01-01T00:00:00+00:00');",
+                [],
+            )
+            .unwrap();
+
+        // Act
+        let mut connection = connection;
+        db_migrations(
+            &mut connection,
+            MigrationContext {
+                default_configs: None,
+                version: 5,
+            },
+        )
+        .unwrap();
+
+        // Assert
+        let (name, repo): (String, Option<String>) = connection
+            .query_row("SELECT name, repo FROM branch", [], |row| {
+                Ok((row.get(0)?, row.get(1)?))
+            })
+            .unwrap();
+
+        assert_eq!(name, "inkan-main");
+        assert_eq!(repo, None);
+    }
+
+    #[test]
+    fn backfill_branch_repos_claims_branches_prefixed_by_the_repo() {
+        // Arrange
+        let (connection, ..) = arrange(MigrationContext {
+            default_configs: None,
+            version: 5,
+        });
+
+        for (name, repo) in [
+            ("inkan-main", None),
+            ("inkan-cli-main", None),
+            ("other-main", None),
+            ("inkan-cli-dev", Some("inkan-cli")),
+        ] {
+            connection
+                .execute(
+                    "INSERT INTO branch (name, ticket, created, repo) VALUES (?1, 'ticket', '2023-01-01T00:00:00+00:00', ?2);",
+                    (name, repo),
+                )
+                .unwrap();
+        }
+
+        // Act
+        backfill_branch_repos(&connection, "inkan").unwrap();
+
+        // Assert
+        assert_eq!(
+            get_branch_repos(&connection),
+            vec![
+                ("inkan-cli-dev".into(), Some("inkan-cli".into())),
+                ("inkan-cli-main".into(), None),
+                ("inkan-main".into(), Some("inkan".into())),
+                ("other-main".into(), None),
+            ]
+        );
+
+        // Act
+        backfill_branch_repos(&connection, "inkan-cli").unwrap();
+
+        // Assert
+        assert_eq!(
+            get_branch_repos(&connection)[1],
+            ("inkan-cli-main".into(), Some("inkan-cli".into()))
+        );
+    }
+
+    fn get_branch_repos(connection: &Connection) -> Vec<(String, Option<String>)> {
+        let mut statement = connection
+            .prepare("SELECT name, repo FROM branch ORDER BY name")
+            .unwrap();
+
+        statement
+            .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap()
+    }
+
     fn get_table_names(connection: &mut Connection) -> Vec<String> {
         let mut statement = connection
             .prepare("SELECT name FROM sqlite_schema WHERE type='table'")
@@ -7,6 +7,8 @@ use crate::domain::{
     models::{Branch, ConfigKey, Template, TemplateStatus},
 };
 
+use super::mappings::branch_data;
+
 pub struct Sqlite {
     connection: Connection,
 }
@@ -16,7 +18,7 @@ impl Sqlite {
         Sqlite { connection }
     }
 
-    pub fn transaction(&mut self) -> Result<Transaction, PersistError> {
+    pub fn transaction(&mut self) -> Result<Transaction<'_>, PersistError> {
         let transaction = self.connection.transaction().map_err(|e| {
             log::error!("Failed to open transaction for sqlite db: {}", &e);
             PersistError::Unknown(e.into())
@@ -34,16 +36,19 @@ impl domain::adapters::Store for Sqlite {
             branch.ticket
         );
 
+        let data = branch_data(branch)?;
+
         self.connection
             .execute(
-                "REPLACE INTO branch (name, ticket, data, created, link, scope) VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+                "REPLACE INTO branch (name, ticket, data, created, link, scope, repo) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
                 (
                     &branch.name,
                     &branch.ticket,
-                    &branch.data,
+                    &data,
                     &branch.created.to_rfc3339(),
                     &branch.link,
-                    &branch.scope
+                    &branch.scope,
+                    &branch.repo
                 ),
             )
             .map_err(|e| PersistError::into_branch_error(format!("Failed to update branch '{}'", branch.name), e))?;
@@ -64,8 +69,8 @@ impl domain::adapters::Store for Sqlite {
         let branch = self
             .connection
             .query_row(
-                "SELECT name, ticket, data, created, link, scope FROM branch where name = ?",
-                [name],
+                "SELECT name, ticket, data, created, link, scope, repo FROM branch WHERE repo = ?1 AND name = ?2",
+                [repo.trim(), &name],
                 |row| Branch::try_from(row),
             )
             .map_err(|e| {
@@ -75,6 +80,49 @@ impl domain::adapters::Store for Sqlite {
         Ok(branch)
     }
 
+    fn get_branches(&self, repo: &str) -> Result<Vec<Branch>, PersistError> {
+        log::info!("retrieve branches for repo '{}'", repo);
+
+        let mut statement = self
+            .connection
+            .prepare(
+                "SELECT name, ticket, data, created, link, scope, repo FROM branch WHERE repo = ?1 ORDER BY created DESC",
+            )
+            .map_err(|e| PersistError::into_branch_error("Failed to retrieve branches", e))?;
+
+        let branches: Vec<_> = statement
+            .query_map([repo.trim()], |row| Branch::try_from(row))
+            .map_err(|e| PersistError::into_branch_error("Failed to retrieve branches", e))?
+            .collect::<Result<_, _>>()
+            .map_err(|e| PersistError::into_branch_error("Failed to retrieve branches", e))?;
+
+        Ok(branches)
+    }
+
+    fn delete_branch(&self, branch: &str, repo: &str) -> Result<(), PersistError> {
+        let name = format!("{}-{}", repo.trim(), branch.trim());
+
+        log::info!("delete branch '{}'", name);
+
+        let deleted = self
+            .connection
+            .execute(
+                "DELETE FROM branch WHERE repo = ?1 AND name = ?2",
+                [repo.trim(), &name],
+            )
+            .map_err(|e| {
+                PersistError::into_branch_error(format!("Failed to delete branch '{name}'"), e)
+            })?;
+
+        if deleted == 0 {
+            return Err(PersistError::NotFound {
+                name: "branch".into(),
+            });
+        }
+
+        Ok(())
+    }
+
     fn persist_template(&self, config: &Template) -> Result<(), PersistError> {
         let key: String = config.key.clone().into();
         let path: String = config.path.to_string();
@@ -183,6 +231,77 @@ impl domain::adapters::Store for Sqlite {
         Ok(configs)
     }
 
+    fn get_template_paths(&self) -> Result<Vec<(ConfigKey, String)>, PersistError> {
+        let mut statement = self
+            .connection
+            .prepare("SELECT key, path FROM config")
+            .map_err(|e| PersistError::into_config_error("Failed to retrieve configs", e))?;
+
+        let paths: Vec<_> = statement
+            .query_map([], |row| {
+                Ok((
+                    ConfigKey::from(row.get::<_, String>(0)?.as_str()),
+                    row.get(1)?,
+                ))
+            })
+            .map_err(|e| PersistError::into_config_error("Failed to retrieve configs", e))?
+            .collect::<Result<_, _>>()
+            .map_err(|e| PersistError::into_config_error("Failed to retrieve configs", e))?;
+
+        Ok(paths)
+    }
+
+    fn remove_template(&self, key: &ConfigKey) -> Result<TemplateStatus, PersistError> {
+        let key: String = key.to_owned().into();
+
+        log::info!("remove config '{}'", &key);
+
+        let status = self
+            .connection
+            .query_row(
+                "DELETE FROM config WHERE key = ?1 RETURNING status",
+                [&key],
+                |row| row.get::<_, String>(0),
+            )
+            .map_err(|e| match e {
+                rusqlite::Error::QueryReturnedNoRows => PersistError::NotFound {
+                    name: "config".into(),
+                },
+                e => {
+                    PersistError::into_config_error(format!("Failed to remove config '{key}'."), e)
+                }
+            })?;
+
+        status.try_into().map_err(|e| PersistError::Corrupted {
+            name: "config status".into(),
+            source: Some(e),
+        })
+    }
+
+    fn rename_template(&self, key: &ConfigKey, name: &ConfigKey) -> Result<(), PersistError> {
+        let (key, name): (String, String) = (key.to_owned().into(), name.to_owned().into());
+
+        log::info!("rename config '{}' to '{}'", &key, &name);
+
+        let renamed = self
+            .connection
+            .execute("UPDATE config SET key = ?1 WHERE key = ?2", [&name, &key])
+            .map_err(|e| {
+                PersistError::into_config_error(
+                    format!("Failed to rename config '{key}' to '{name}'."),
+                    e,
+                )
+            })?;
+
+        if renamed == 0 {
+            return Err(PersistError::NotFound {
+                name: "config".into(),
+            });
+        }
+
+        Ok(())
+    }
+
     fn close(self) -> anyhow::Result<()> {
         log::info!("closing sqlite connection");
 
@@ -196,7 +315,10 @@ impl domain::adapters::Store for Sqlite {
 
 #[cfg(test)]
 mod tests {
-    use std::{collections::HashMap, path::Path};
+    use std::{
+        collections::{BTreeMap, HashMap},
+        path::Path,
+    };
 
     use crate::adapters::git::{Git, GitCommand};
     use crate::domain::models::path::AbsolutePath;
@@ -293,7 +415,7 @@ mod tests {
             .with_context(|| "Expected to find a matching branch")?;
 
         // Act
-        let branch = context.store.get_branch(&random_key, &repo)?;
+        let branch = context.store.get_branch(random_key, &repo)?;
 
         context.close()?;
 
@@ -337,14 +459,15 @@ mod tests {
         let key = format!("{}-{}", repo.trim(), name.trim());
 
         store.connection.execute(
-            "INSERT INTO branch (name, ticket, data, created, link, scope) VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            "INSERT INTO branch (name, ticket, data, created, link, scope, repo) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
             (
                 &key,
                 &Faker.fake::<String>(),
                 None::<Vec<u8>>,
                 "invalid_date",
                 &Faker.fake::<String>(),
-                &Faker.fake::<String>()
+                &Faker.fake::<String>(),
+                &repo
             )
         ).unwrap();
 
@@ -362,6 +485,173 @@ mod tests {
         assert!(matches!(error, PersistError::Corrupted { name, .. } if name == "branch" ));
     }
 
+    #[test]
+    fn invalid_stored_variables_data_returns_corrupted_error() {
+        // Arrange
+        let connection = setup_db().unwrap();
+        let store = Sqlite { connection };
+        let name = Faker.fake::<String>();
+        let repo = Faker.fake::<String>();
+        let key = format!("{}-{}", repo.trim(), name.trim());
+
+        store.connection.execute(
+            "INSERT INTO branch (name, ticket, data, created, link, scope, repo) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+            (
+                &key,
+                &Faker.fake::<String>(),
+                Some(b"- not\n- a map".to_vec()),
+                Utc::now().to_rfc3339(),
+                &Faker.fake::<String>(),
+                &Faker.fake::<String>(),
+                &repo
+            )
+        ).unwrap();
+
+        // Act
+        let error = store.get_branch(&name, &repo).unwrap_err();
+
+        // Assert
+        assert!(matches!(error, PersistError::Corrupted { name, .. } if name == "branch" ));
+    }
+
+    #[test]
+    fn persist_branch_without_variables_stores_no_data() -> anyhow::Result<()> {
+        // Arrange
+        let branch = Branch {
+            variables: BTreeMap::new(),
+            ..fake_branch(None, None)?
+        };
+        let store = Sqlite::new(setup_db()?);
+
+        // Act
+        store.persist_branch(&branch)?;
+
+        // Assert
+        let data: Option<Vec<u8>> =
+            store
+                .connection
+                .query_row("SELECT data FROM branch", [], |row| row.get(0))?;
+
+        assert_eq!(data, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn get_branches_retrieves_branches_for_the_repo_most_recent_first() -> anyhow::Result<()> {
+        // Arrange
+        let store = Sqlite::new(setup_db()?);
+        let repo = Faker.fake::<String>();
+
+        let older = Branch {
+            created: Utc::now() - chrono::Duration::days(1),
+            ..fake_branch(None, Some(repo.clone()))?
+        };
+        let newer = fake_branch(None, Some(repo.clone()))?;
+
+        insert_branch(&store.connection, &older);
+        insert_branch(&store.connection, &newer);
+        insert_branch(&store.connection, &fake_branch(None, None)?);
+
+        // Act
+        let branches = store.get_branches(&repo)?;
+
+        // Assert
+        assert_eq!(branches, vec![newer, older]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn get_branches_excludes_repos_prefixed_by_the_repo_name() -> anyhow::Result<()> {
+        // Arrange
+        let store = Sqlite::new(setup_db()?);
+
+        let branch = fake_branch(Some("main".into()), Some("inkan".into()))?;
+        insert_branch(&store.connection, &branch);
+        insert_branch(
+            &store.connection,
+            &fake_branch(Some("main".into()), Some("inkan-cli".into()))?,
+        );
+
+        // Act
+        let branches = store.get_branches("inkan")?;
+
+        // Assert
+        assert_eq!(branches, vec![branch]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn get_branch_only_retrieves_the_branch_for_the_repo() -> anyhow::Result<()> {
+        // Arrange
+        let store = Sqlite::new(setup_db()?);
+
+        // Stored as `inkan-cli-main` the same name as branch `cli-main` within `inkan`.
+        let branch = fake_branch(Some("main".into()), Some("inkan-cli".into()))?;
+        insert_branch(&store.connection, &branch);
+
+        // Act
+        let error = store.get_branch("cli-main", "inkan").unwrap_err();
+
+        // Assert
+        assert!(matches!(error, PersistError::NotFound { name } if name == "branch"));
+        assert_eq!(store.get_branch("main", "inkan-cli")?, branch);
+
+        Ok(())
+    }
+
+    #[test]
+    fn delete_branch_removes_only_that_branch() -> anyhow::Result<()> {
+        // Arrange
+        let store = Sqlite::new(setup_db()?);
+        let (name, repo) = (Faker.fake::<String>(), Faker.fake::<String>());
+
+        let branch = fake_branch(Some(name.clone()), Some(repo.clone()))?;
+        let other = fake_branch(None, Some(repo.clone()))?;
+        insert_branch(&store.connection, &branch);
+        insert_branch(&store.connection, &other);
+
+        // Act
+        store.delete_branch(&name, &repo)?;
+
+        // Assert
+        assert_eq!(select_branch_row(&store.connection)?, other);
+
+        Ok(())
+    }
+
+    #[test]
+    fn delete_branch_does_not_remove_branches_of_another_repo() -> anyhow::Result<()> {
+        // Arrange
+        let store = Sqlite::new(setup_db()?);
+
+        // Stored as `inkan-cli-main` the same name as branch `cli-main` within `inkan`.
+        let branch = fake_branch(Some("main".into()), Some("inkan-cli".into()))?;
+        insert_branch(&store.connection, &branch);
+
+        // Act
+        let error = store.delete_branch("cli-main", "inkan").unwrap_err();
+
+        // Assert
+        assert!(matches!(error, PersistError::NotFound { name } if name == "branch"));
+        assert_eq!(select_branch_row(&store.connection)?, branch);
+
+        Ok(())
+    }
+
+    #[test]
+    fn delete_branch_that_does_not_exist_is_not_found() {
+        let store = Sqlite::new(setup_db().unwrap());
+
+        let error = store
+            .delete_branch(&Faker.fake::<String>(), &Faker.fake::<String>())
+            .unwrap_err();
+
+        assert!(matches!(error, PersistError::NotFound { name } if name == "branch"));
+    }
+
     #[test]
     fn get_branch_trims_branch_name_before_retrieving() -> anyhow::Result<()> {
         // Arrange
@@ -664,6 +954,104 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn get_template_paths_includes_paths_that_are_no_longer_valid() -> anyhow::Result<()> {
+        // Arrange
+        let connection = setup_db()?;
+        let config = fake_config();
+        insert_config(&connection, &config)?;
+        insert_raw_config(&connection, "removed", "/no/longer/exists.yml", "DISABLED");
+
+        let store = Sqlite::new(connection);
+
+        // Act
+        let paths = store.get_template_paths()?;
+
+        // Assert
+        assert_eq!(
+            paths,
+            vec![
+                (config.key, config.path.to_string()),
+                (
+                    ConfigKey::User("removed".into()),
+                    "/no/longer/exists.yml".into()
+                ),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn remove_config_deletes_the_config() -> anyhow::Result<()> {
+        // Arrange
+        let connection = setup_db()?;
+        let (config, other) = (fake_config(), fake_config());
+        insert_config(&connection, &config)?;
+        insert_config(&connection, &other)?;
+
+        let store = Sqlite::new(connection);
+
+        // Act
+        let status = store.remove_template(&config.key)?;
+
+        // Assert
+        assert_eq!(status, config.status);
+        assert_eq!(select_all_config(&store.connection)?, vec![other]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn remove_config_that_does_not_exist_is_not_found() {
+        let store = Sqlite::new(setup_db().unwrap());
+
+        let error = store
+            .remove_template(&ConfigKey::User(Faker.fake()))
+            .unwrap_err();
+
+        assert!(matches!(error, PersistError::NotFound { name } if name == "config"));
+    }
+
+    #[test]
+    fn rename_config_keeps_the_path_and_status() -> anyhow::Result<()> {
+        // Arrange
+        let connection = setup_db()?;
+        let config = fake_config();
+        insert_config(&connection, &config)?;
+
+        let store = Sqlite::new(connection);
+        let name = ConfigKey::User(Faker.fake());
+
+        // Act
+        store.rename_template(&config.key, &name)?;
+
+        // Assert
+        assert_eq!(
+            select_all_config(&store.connection)?,
+            vec![Template {
+                key: name,
+                ..config
+            }]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn rename_config_that_does_not_exist_is_not_found() {
+        let store = Sqlite::new(setup_db().unwrap());
+
+        let error = store
+            .rename_template(
+                &ConfigKey::User(Faker.fake()),
+                &ConfigKey::User(Faker.fake()),
+            )
+            .unwrap_err();
+
+        assert!(matches!(error, PersistError::NotFound { name } if name == "config"));
+    }
+
     fn valid_path() -> AbsolutePath {
         let path = Path::new(".").to_owned();
         dunce::canonicalize(path)
@@ -688,14 +1076,15 @@ mod tests {
 
     fn insert_branch(connection: &Connection, branch: &Branch) {
         connection.execute(
-            "INSERT INTO branch (name, ticket, data, created, link, scope) VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            "INSERT INTO branch (name, ticket, data, created, link, scope, repo) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
             (
                 &branch.name,
                 &branch.ticket,
-                &branch.data,
+                branch_data(branch).unwrap(),
                 &branch.created.to_rfc3339(),
                 &branch.link,
-                &branch.scope
+                &branch.scope,
+                &branch.repo
             ),
         ).unwrap();
     }
@@ -725,17 +1114,14 @@ mod tests {
         let name = name.unwrap_or(Faker.fake());
         let repo = repo.unwrap_or(Faker.fake());
 
-        Ok(Branch::new(
-            &name,
-            &repo,
-            Faker.fake(),
-            Faker.fake(),
-            Faker.fake(),
-        ))
+        Ok(Branch {
+            variables: Faker.fake(),
+            ..Branch::new(&name, &repo, Faker.fake(), Faker.fake(), Faker.fake())
+        })
     }
 
     fn select_branch_row(conn: &Connection) -> anyhow::Result<Branch> {
-        let (name, ticket, data, created, link, scope) =
+        let (name, ticket, data, created, link, scope, repo) =
             conn.query_row("SELECT * FROM branch", [], |row| {
                 Ok((
                     row.get::<_, String>(0)?,
@@ -744,17 +1130,23 @@ mod tests {
                     row.get::<_, String>(3)?,
                     row.get::<_, Option<String>>(4)?,
                     row.get::<_, Option<String>>(5)?,
+                    row.get::<_, Option<String>>(6)?,
                 ))
             })?;
         let created = DateTime::parse_from_rfc3339(&created)?.with_timezone(&Utc);
+        let variables = match data {
+            Some(data) => serde_yaml::from_slice(&data)?,
+            None => BTreeMap::new(),
+        };
 
         Ok(Branch {
             name,
             ticket,
-            data,
+            variables,
             created,
             link,
             scope,
+            repo,
         })
     }
 
@@ -793,7 +1185,7 @@ mod tests {
             &mut conn,
             MigrationContext {
                 default_configs: None,
-                version: 4,
+                version: 5,
             },
         )?;
         Ok(conn)
@@ -1,6 +1,7 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use anyhow::Context;
+use colored::Colorize;
 use directories::ProjectDirs;
 use rusqlite::Connection;
 
@@ -8,12 +9,10 @@ use crate::{
     adapters::{sqlite::Sqlite, Git},
     domain::{
         adapters::{Git as _, GitSystem, Store},
-        errors::{Errors, UserInputError},
-        models::{
-            path::{AbsolutePath, PathType},
-            ConfigKey, Template, TemplateStatus,
-        },
+        errors::{Errors, PersistError, UserInputError},
+        models::{path::AbsolutePath, ConfigKey, Template, TemplateStatus},
     },
+    template_config::{find_local_config, LOCAL_CONFIG_FILES},
 };
 
 pub struct AppTemplate {
@@ -37,18 +36,40 @@ impl AppTemplate {
                     })
                 })?,
             }),
-            None => store.get_template(None).map_err(|e| Errors::Configuration {
-                message: "Failed to get current 'active' config".into(),
-                source: e.into(),
-            }),
+            None => Self::active_config(store),
         }?;
 
         let git_root_dir = git.root_directory().map_err(Errors::Git)?;
-        let config = Self::map_config_overrides(config, git_root_dir)?;
+        let current_dir = std::env::current_dir().map_err(|e| Errors::Configuration {
+            message: "Failed to get the current directory".into(),
+            source: e.into(),
+        })?;
+        let config = Self::map_config_overrides(config, git_root_dir, &current_dir)?;
 
         Ok(AppTemplate { config })
     }
 
+    /// Falls back to the default config when the active config path is no longer valid so
+    /// it can still be fixed with `inkan config verify` & `inkan config remove`.
+    fn active_config(store: &Sqlite) -> Result<Template, Errors> {
+        let config = match store.get_template(None) {
+            // Printed to stderr so piped output i.e `config show` isn't polluted.
+            Err(PersistError::Corrupted { .. }) => {
+                eprintln!(
+                    "{}: (Active) configuration path is no longer valid falling back to the default configuration, run 'inkan config verify' for details.\n",
+                    "⚠️ Warning".yellow(),
+                );
+                store.get_template(Some(ConfigKey::Default.into()))
+            }
+            config => config,
+        };
+
+        config.map_err(|e| Errors::Configuration {
+            message: "Failed to get current 'active' config".into(),
+            source: e.into(),
+        })
+    }
+
     pub fn db_connection() -> anyhow::Result<Connection> {
         let db_file = Self::template_config_dir()?.join("db");
 
@@ -64,20 +85,33 @@ impl AppTemplate {
         Ok(project_dir.config_dir().to_owned())
     }
 
-    pub fn join_config_filename(repo_root_dir: &AbsolutePath) -> Result<AbsolutePath, Errors> {
-        repo_root_dir
-            .join(".inkan.yml", PathType::File)
-            .map_err(|e| Errors::Configuration {
-                message: "Failed to load repositories local '.inkan.yml'".into(),
-                source: e.into(),
+    /// Nearest local config file i.e `.inkan.yml` or `.inkan.toml` walking up from the current directory to the repository root.
+    pub fn find_config_filename(
+        current_dir: &Path,
+        repo_root_dir: &AbsolutePath,
+    ) -> Result<AbsolutePath, Errors> {
+        let start = match current_dir.starts_with(repo_root_dir) {
+            true => current_dir,
+            false => repo_root_dir.as_ref(),
+        };
+
+        find_local_config(start)
+            .filter(|config| config.as_ref().starts_with(repo_root_dir))
+            .ok_or_else(|| Errors::Configuration {
+                message: format!(
+                    "Failed to load repositories local config one of [{}]",
+                    LOCAL_CONFIG_FILES.join(", ")
+                ),
+                source: anyhow::anyhow!("No local config file found"),
             })
     }
 
     fn map_config_overrides(
         config: Template,
         repo_root_dir: AbsolutePath,
+        current_dir: &Path,
     ) -> Result<Template, Errors> {
-        let local_config = AppTemplate::join_config_filename(&repo_root_dir);
+        let local_config = AppTemplate::find_config_filename(current_dir, &repo_root_dir);
 
         match (config.key.clone(), &local_config) {
             // Once off override takes priority 1
@@ -118,7 +152,10 @@ mod tests {
 
     use crate::{
         adapters::GitCommand,
-        domain::{adapters, models::TemplateStatus},
+        domain::{
+            adapters,
+            models::{path::PathType, TemplateStatus},
+        },
     };
 
     use super::*;
@@ -136,7 +173,8 @@ mod tests {
                 path: once_path.clone(),
                 status: TemplateStatus::Active,
             },
-            valid_repo_dir,
+            valid_repo_dir.clone(),
+            valid_repo_dir.as_ref(),
         )
         .unwrap();
 
@@ -151,7 +189,7 @@ mod tests {
         // Mock repo 'local' level configuration
         let repo_root_with_config = std::env::temp_dir();
         let config_repo = repo_root_with_config.join(".inkan.yml");
-        let path_buf: PathBuf = config_repo.clone().into();
+        let path_buf: PathBuf = config_repo.clone();
         std::fs::File::create(&path_buf).unwrap();
 
         for key in [ConfigKey::Default, ConfigKey::User(Faker.fake())] {
@@ -163,6 +201,7 @@ mod tests {
                     status: TemplateStatus::Active,
                 },
                 repo_root_with_config.clone().try_into().unwrap(),
+                &repo_root_with_config,
             )
             .unwrap();
 
@@ -176,6 +215,30 @@ mod tests {
         std::fs::remove_file(path_buf).unwrap();
     }
 
+    #[test]
+    fn repo_dir_local_config_file_can_be_toml_or_json() {
+        for filename in [".inkan.toml", ".inkan.json"] {
+            // Arrange
+            let repo_root_with_config = std::env::temp_dir().join(Faker.fake::<String>());
+            std::fs::create_dir_all(&repo_root_with_config).unwrap();
+            let config_repo = repo_root_with_config.join(filename);
+            std::fs::File::create(&config_repo).unwrap();
+
+            // Act
+            let actual = AppTemplate::find_config_filename(
+                &repo_root_with_config,
+                &repo_root_with_config.clone().try_into().unwrap(),
+            )
+            .unwrap();
+
+            // Assert
+            let path: PathBuf = actual.into();
+            assert_eq!(config_repo, path);
+
+            std::fs::remove_dir_all(repo_root_with_config).unwrap();
+        }
+    }
+
     #[test]
     fn user_sets_config_file_and_no_config_or_once_off_config_priority_3() {
         let user_path = valid_file_path();
@@ -188,7 +251,8 @@ mod tests {
                 path: user_path.clone(),
                 status: TemplateStatus::Active,
             },
-            repo_non_existing,
+            repo_non_existing.clone(),
+            repo_non_existing.as_ref(),
         )
         .unwrap();
 
@@ -208,7 +272,8 @@ mod tests {
                 path: default_path.clone(),
                 status: TemplateStatus::Active,
             },
-            repo_non_existing,
+            repo_non_existing.clone(),
+            repo_non_existing.as_ref(),
         )
         .unwrap();
 
@@ -217,6 +282,36 @@ mod tests {
         assert_eq!(TemplateStatus::Active, config.status);
     }
 
+    #[test]
+    fn nearest_local_config_walking_up_from_the_current_directory_is_found() {
+        // Arrange
+        let repo_root = std::env::temp_dir().join(Faker.fake::<String>());
+        let package = repo_root.join("packages").join("api");
+        let nested = package.join("src");
+        std::fs::create_dir_all(&nested).unwrap();
+        std::fs::create_dir(repo_root.join(".git")).unwrap();
+
+        let root_config = repo_root.join(".inkan.yml");
+        std::fs::File::create(&root_config).unwrap();
+        let abs_repo_root: AbsolutePath = repo_root.clone().try_into().unwrap();
+
+        // Act & Assert
+        let actual = AppTemplate::find_config_filename(&nested, &abs_repo_root).unwrap();
+        assert_eq!(root_config, PathBuf::from(actual));
+
+        let package_config = package.join(".inkan.toml");
+        std::fs::File::create(&package_config).unwrap();
+
+        let actual = AppTemplate::find_config_filename(&nested, &abs_repo_root).unwrap();
+        assert_eq!(package_config, PathBuf::from(actual));
+
+        // Directories outside of the repository start from the repository root.
+        let actual = AppTemplate::find_config_filename(&std::env::temp_dir(), &abs_repo_root);
+        assert_eq!(root_config, PathBuf::from(actual.unwrap()));
+
+        std::fs::remove_dir_all(repo_root).unwrap();
+    }
+
     fn repo_directory() -> PathBuf {
         PathBuf::from(env!("CARGO_MANIFEST_DIR"))
     }
@@ -0,0 +1,79 @@
+use colored::Colorize;
+
+use crate::{
+    app_context::AppContext,
+    domain::{
+        adapters::{
+            prompt::{Prompter, SelectItem},
+            CheckoutStatus, Git, Store,
+        },
+        commands::branches::{self, BranchContext},
+        errors::Errors,
+    },
+    entry::Interactive,
+};
+
+pub fn handler<G: Git, S: Store, P: Prompter>(
+    context: &AppContext<G, S>,
+    prompt: P,
+) -> Result<(), Errors> {
+    let branches = branches::handler(&context.git, &context.store)?;
+
+    if branches.is_empty() {
+        println!("No branch context has been stored for this repository.");
+        return Ok(());
+    }
+
+    for branch in &branches {
+        print_branch(branch);
+    }
+
+    // Only branches that still exist can be switched to.
+    let options: Vec<SelectItem<String>> = branches
+        .into_iter()
+        .filter(|branch| branch.exists && !branch.current)
+        .map(|branch| SelectItem {
+            description: Some(branch.branch.ticket),
+            value: branch.name.clone(),
+            name: branch.name,
+            group: None,
+        })
+        .collect();
+
+    if context.interactive == Interactive::Disable || options.is_empty() {
+        return Ok(());
+    }
+
+    println!();
+    let selected = prompt
+        .select("Switch branch", options)
+        .map_err(Errors::UserInput)?;
+
+    context
+        .git
+        .checkout(&selected.value, CheckoutStatus::Existing)
+        .map_err(Errors::Git)?;
+
+    Ok(())
+}
+
+fn print_branch(context: &BranchContext) {
+    let branch = &context.branch;
+
+    let mut details = vec![format!("ticket {}", branch.ticket)];
+    details.extend(branch.scope.as_ref().map(|scope| format!("scope {scope}")));
+    details.extend(branch.link.as_ref().map(|link| format!("link {link}")));
+    details.push(format!("created {}", branch.created.format("%Y-%m-%d")));
+
+    let details = details.join(" | ");
+
+    match (context.exists, context.current) {
+        (true, true) => println!(
+            "🟢 {} (Current) ➜ {}",
+            context.name.green(),
+            details.italic()
+        ),
+        (true, false) => println!("🟢 {} ➜ {}", context.name.green(), details.italic()),
+        (false, _) => println!("🔴 {} (Deleted) ➜ {}", context.name, details.italic()),
+    }
+}
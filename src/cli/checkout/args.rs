@@ -2,8 +2,14 @@ use clap::Args;
 use std::result::Result::Ok;
 
 use crate::{
-    domain::{adapters::prompt::Prompter, commands::checkout::Checkout, errors::UserInputError},
+    domain::{
+        adapters::prompt::{Prompter, SelectItem},
+        commands::checkout::Checkout,
+        errors::UserInputError,
+        template::Ast,
+    },
     entry::Interactive,
+    template_config::BranchConfig,
     utils::or_else_try::OrElseTry,
 };
 
@@ -24,14 +30,39 @@ pub struct Arguments {
     /// Issue ticket number link.
     #[clap(short, long, value_parser)]
     pub link: Option<String>,
+
+    /// Type of branch i.e `feature` used by the `{type}` branch template placeholder.
+    #[clap(long = "type", value_parser)]
+    pub branch_type: Option<String>,
+
+    /// Short title of the changes used by the `{title}` branch template placeholder.
+    #[clap(long, value_parser)]
+    pub title: Option<String>,
+
+    /// Ref to create the branch from i.e `main`, defaults to the configured branch base otherwise the current `HEAD`.
+    #[clap(short, long, value_parser)]
+    pub from: Option<String>,
 }
 
 impl Arguments {
     pub fn try_into_domain<P: Prompter>(
         &self,
+        branch: Option<&BranchConfig>,
         prompt: P,
         interactive: &Interactive,
     ) -> Result<Checkout, UserInputError> {
+        let types = branch
+            .map(|branch| branch.types.as_slice())
+            .unwrap_or_default();
+        let from = self
+            .from
+            .clone()
+            .or_else(|| branch.and_then(|branch| branch.base.clone()));
+
+        if let Some(branch_type) = &self.branch_type {
+            validate_branch_type(branch_type, types)?;
+        }
+
         let domain = match interactive {
             Interactive::Enable => Checkout {
                 name: self.name.clone(),
@@ -47,12 +78,31 @@ impl Arguments {
                     .link
                     .clone()
                     .or_else_try(|| prompt.text("Link", None))?,
+                // Type & title are only asked for when the branch template makes use of them.
+                branch_type: match uses_placeholder(branch, "type") {
+                    true => self
+                        .branch_type
+                        .clone()
+                        .or_else_try(|| prompt_branch_type(types, &prompt))?,
+                    false => self.branch_type.clone(),
+                },
+                title: match uses_placeholder(branch, "title") {
+                    true => self
+                        .title
+                        .clone()
+                        .or_else_try(|| prompt.text("Title", None))?,
+                    false => self.title.clone(),
+                },
+                from,
             },
             Interactive::Disable => Checkout {
                 name: self.name.clone(),
                 ticket: self.ticket.clone(),
                 scope: self.scope.clone(),
                 link: self.link.clone(),
+                branch_type: self.branch_type.clone(),
+                title: self.title.clone(),
+                from,
             },
         };
 
@@ -60,6 +110,48 @@ impl Arguments {
     }
 }
 
+fn validate_branch_type(branch_type: &str, types: &[String]) -> Result<(), UserInputError> {
+    if types.is_empty() || types.iter().any(|t| t == branch_type) {
+        return Ok(());
+    }
+
+    Err(UserInputError::Validation {
+        name: "type".into(),
+        message: format!(
+            "Unknown branch type {branch_type:?} expected one of [{}]",
+            types.join(", ")
+        ),
+    })
+}
+
+fn prompt_branch_type<P: Prompter>(
+    types: &[String],
+    prompt: &P,
+) -> Result<Option<String>, UserInputError> {
+    if types.is_empty() {
+        return Ok(None);
+    }
+
+    let types = types
+        .iter()
+        .map(|branch_type| SelectItem {
+            name: branch_type.clone(),
+            value: branch_type.clone(),
+            description: None,
+            group: None,
+        })
+        .collect();
+
+    Ok(Some(prompt.select("Type", types)?.value))
+}
+
+fn uses_placeholder(branch: Option<&BranchConfig>, name: &str) -> bool {
+    branch
+        .and_then(|branch| Ast::parse(&branch.content).ok())
+        .map(|ast| ast.names().contains(&name))
+        .unwrap_or(false)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -78,13 +170,16 @@ mod tests {
 
         let actual = args
             .clone()
-            .try_into_domain(prompt, &Interactive::Disable)?;
+            .try_into_domain(None, prompt, &Interactive::Disable)?;
 
         let expected = Checkout {
             name: args.name.clone(),
             ticket: args.ticket.clone(),
             scope: args.scope.clone(),
             link: args.link.clone(),
+            branch_type: args.branch_type.clone(),
+            title: args.title.clone(),
+            from: args.from.clone(),
         };
 
         assert_eq!(expected, actual);
@@ -98,6 +193,8 @@ mod tests {
             ticket: None,
             scope: None,
             link: None,
+            branch_type: None,
+            title: None,
             ..fake_args()
         };
 
@@ -108,13 +205,18 @@ mod tests {
             text_result: Ok(text_prompt.clone()),
         };
 
-        let actual = args.clone().try_into_domain(prompt, &Interactive::Enable)?;
+        let actual = args
+            .clone()
+            .try_into_domain(None, prompt, &Interactive::Enable)?;
 
         let expected = Checkout {
             name: args.name.clone(),
             ticket: text_prompt.clone(),
             scope: text_prompt.clone(),
             link: text_prompt.clone(),
+            branch_type: None,
+            title: None,
+            from: args.from.clone(),
         };
 
         assert_eq!(expected, actual);
@@ -137,13 +239,18 @@ mod tests {
             text_result: Err(anyhow::anyhow!("text should not be called")),
         };
 
-        let actual = args.clone().try_into_domain(prompt, &Interactive::Enable)?;
+        let actual = args
+            .clone()
+            .try_into_domain(None, prompt, &Interactive::Enable)?;
 
         let expected = Checkout {
             name: args.name.clone(),
             ticket: args.ticket.clone(),
             scope: args.scope.clone(),
             link: args.link.clone(),
+            branch_type: args.branch_type.clone(),
+            title: args.title.clone(),
+            from: args.from.clone(),
         };
 
         assert_eq!(expected, actual);
@@ -151,6 +258,157 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn try_into_domain_with_interactive_selects_type_and_prompts_title_used_by_branch(
+    ) -> anyhow::Result<()> {
+        let args = Arguments {
+            branch_type: None,
+            title: None,
+            ..fake_args()
+        };
+
+        let branch = fake_branch_config("{type}/{ticket_num}-{title|slug}");
+        let text_prompt = Faker.fake::<Option<String>>();
+
+        let prompt = PromptTest {
+            select_index: Ok(1),
+            text_result: Ok(text_prompt.clone()),
+        };
+
+        let actual = args.try_into_domain(Some(&branch), prompt, &Interactive::Enable)?;
+
+        assert_eq!(actual.branch_type, Some("hotfix".into()));
+        assert_eq!(actual.title, text_prompt);
+
+        Ok(())
+    }
+
+    #[test]
+    fn try_into_domain_with_interactive_title_is_not_prompted_if_unused_by_branch(
+    ) -> anyhow::Result<()> {
+        let args = Arguments {
+            branch_type: Some("feature".into()),
+            title: None,
+            ..fake_args()
+        };
+
+        let prompt = PromptTest {
+            select_index: Err(anyhow::anyhow!("select should not be called")),
+            text_result: Ok(Faker.fake()),
+        };
+
+        let actual = args.try_into_domain(
+            Some(&fake_branch_config("{type}/{ticket_num}")),
+            prompt,
+            &Interactive::Enable,
+        )?;
+
+        assert_eq!(actual.branch_type, Some("feature".into()));
+        assert_eq!(actual.title, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn try_into_domain_with_interactive_type_is_not_selected_if_unused_by_branch(
+    ) -> anyhow::Result<()> {
+        let args = Arguments {
+            branch_type: None,
+            title: Some("login".into()),
+            ..fake_args()
+        };
+
+        let prompt = PromptTest {
+            select_index: Err(anyhow::anyhow!("select should not be called")),
+            text_result: Ok(Faker.fake()),
+        };
+
+        let actual = args.try_into_domain(
+            Some(&fake_branch_config("{ticket_num}-{title|slug}")),
+            prompt,
+            &Interactive::Enable,
+        )?;
+
+        assert_eq!(actual.branch_type, None);
+        assert_eq!(actual.title, Some("login".into()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn try_into_domain_defaults_from_to_the_configured_branch_base() -> anyhow::Result<()> {
+        let args = Arguments {
+            from: None,
+            branch_type: None,
+            ..fake_args()
+        };
+
+        let branch = BranchConfig {
+            base: Some("origin/main".into()),
+            ..fake_branch_config("{branch_name}")
+        };
+
+        let prompt = PromptTest {
+            select_index: Err(anyhow::anyhow!("select should not be called")),
+            text_result: Err(anyhow::anyhow!("text should not be called")),
+        };
+
+        let actual = args
+            .clone()
+            .try_into_domain(Some(&branch), prompt, &Interactive::Disable)?;
+        assert_eq!(actual.from, Some("origin/main".into()));
+
+        let args = Arguments {
+            from: Some("develop".into()),
+            ..args
+        };
+        let prompt = PromptTest {
+            select_index: Err(anyhow::anyhow!("select should not be called")),
+            text_result: Err(anyhow::anyhow!("text should not be called")),
+        };
+
+        let actual = args.try_into_domain(Some(&branch), prompt, &Interactive::Disable)?;
+        assert_eq!(actual.from, Some("develop".into()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn try_into_domain_with_unknown_branch_type_errors() {
+        let args = Arguments {
+            branch_type: Some("chore".into()),
+            ..fake_args()
+        };
+
+        let prompt = PromptTest {
+            select_index: Err(anyhow::anyhow!("select should not be called")),
+            text_result: Err(anyhow::anyhow!("text should not be called")),
+        };
+
+        let error = args
+            .try_into_domain(
+                Some(&fake_branch_config("{type}/{branch_name}")),
+                prompt,
+                &Interactive::Disable,
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "Invalid input \"type\" found unknown branch type \"chore\" expected one of [feature, hotfix]"
+        );
+    }
+
+    fn fake_branch_config(content: &str) -> BranchConfig {
+        BranchConfig {
+            content: content.into(),
+            separator: "-".into(),
+            max_length: None,
+            types: vec!["feature".into(), "hotfix".into()],
+            base: None,
+        }
+    }
+
     pub struct PromptTest {
         select_index: anyhow::Result<usize>,
         text_result: anyhow::Result<Option<String>>,
@@ -174,12 +432,15 @@ mod tests {
         ) -> Result<SelectItem<T>, UserInputError> {
             let name: String = name.into();
             match &self.select_index {
-                Ok(index) => options.into_iter().nth(index.clone()).ok_or_else(|| {
-                    UserInputError::Validation {
-                        name: name.clone(),
-                        message: "An error occurred in the mock prompter".into(),
-                    }
-                }),
+                Ok(index) => {
+                    options
+                        .into_iter()
+                        .nth(*index)
+                        .ok_or_else(|| UserInputError::Validation {
+                            name: name.clone(),
+                            message: "An error occurred in the mock prompter".into(),
+                        })
+                }
                 Err(_) => Err(UserInputError::Validation {
                     name: name.clone(),
                     message: "An error occurred in the mock prompter".into(),
@@ -194,6 +455,9 @@ mod tests {
             ticket: Faker.fake(),
             scope: Faker.fake(),
             link: Faker.fake(),
+            branch_type: Faker.fake(),
+            title: Faker.fake(),
+            from: Faker.fake(),
         }
     }
 }
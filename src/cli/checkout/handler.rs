@@ -1,7 +1,9 @@
+use colored::Colorize;
+
 use crate::{
     app_context::AppContext,
     domain::{
-        adapters::{prompt::Prompter, Git, Store},
+        adapters::{prompt::Prompter, CheckoutStatus, Git, Store},
         commands::checkout,
         errors::Errors,
     },
@@ -15,13 +17,57 @@ pub fn handler<G: Git, S: Store, P: Prompter>(
     args: Arguments,
     prompt: P,
 ) -> Result<(), Errors> {
+    let template = TemplateConfig::load(&context.config, &context.store)?;
+
     let checkout = args
-        .try_into_domain(prompt, &context.interactive)
+        .try_into_domain(template.branch.as_ref(), prompt, &context.interactive)
         .map_err(Errors::UserInput)?;
 
-    let template = TemplateConfig::new(&context.config.path)?;
+    let from = checkout.from.clone();
+
+    if let Some(from) = &from {
+        dirty_working_tree_warning(&context.git, from);
+    }
+
+    let (_, branch_name, status) =
+        checkout::handler(&context.git, &context.store, template, checkout)?;
+
+    if let (CheckoutStatus::Existing, Some(from)) = (status, from) {
+        println!(
+            "{}: Branch {} already exists, checked out without branching from {}.\n",
+            "⚠️ Warning".yellow(),
+            branch_name.name.green(),
+            from.green()
+        );
+    }
 
-    checkout::handler(&context.git, &context.store, template, checkout)?;
+    if !branch_name.changes.is_empty() {
+        println!(
+            "{}: Branch name changed to {} to be a valid git ref:",
+            "⚠️ Warning".yellow(),
+            branch_name.name.green()
+        );
+
+        for change in branch_name.changes {
+            println!("- {change}");
+        }
+    }
 
     Ok(())
 }
+
+/// Uncommitted changes are carried over onto the new branch which is unlikely to be wanted when branching from another ref.
+fn dirty_working_tree_warning<G: Git>(git: &G, from: &str) {
+    let dirty = git
+        .is_dirty()
+        .map_err(|e| log::warn!("failed to check working tree status: {}", e))
+        .unwrap_or(false);
+
+    if dirty {
+        println!(
+            "{}: Working tree has uncommitted changes which will be carried over when branching from {}.\n",
+            "⚠️ Warning".yellow(),
+            from.green()
+        );
+    }
+}
@@ -8,7 +8,7 @@ use crate::{
     },
 };
 
-use super::{checkout, commit, context, template};
+use super::{branches, checkout, commit, context, gc, template};
 
 #[derive(Debug, Clone, Subcommand)]
 pub enum Commands {
@@ -18,8 +18,12 @@ pub enum Commands {
     Checkout(checkout::Arguments),
     /// Add or update the ticket number related to the current branch.
     Context(context::Arguments),
+    /// List the stored context for each branch within the current repository & switch to one.
+    Branches,
+    /// Remove stored context for branches that no longer exist within the current repository.
+    Gc(gc::Arguments),
     /// Get or Set active template.
-    #[clap(subcommand)]
+    #[clap(subcommand, visible_alias = "config")]
     Template(template::SubCommands),
 }
 
@@ -33,6 +37,8 @@ impl Commands {
             Commands::Checkout(args) => checkout::handler(context, args, prompt),
             Commands::Context(args) => context::handler(context, args, prompt),
             Commands::Commit(args) => commit::handler(context, args, prompt),
+            Commands::Branches => branches::handler(context, prompt),
+            Commands::Gc(args) => gc::handler(context, args),
             Commands::Template(args) => template::handler(
                 &mut context.store,
                 &context.config,
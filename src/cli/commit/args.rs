@@ -1,23 +1,24 @@
-use std::{collections::HashMap, fmt::Debug};
+use std::fmt::Debug;
 
 use clap::Args;
 
 use crate::{
     cli::context,
     domain::{
-        adapters::prompt::{Prompter, SelectItem},
-        commands::commit::Commit,
+        adapters::prompt::{Prompter, SelectItem, TextPrompt},
+        commands::{commit::Commit, context::Context as DomainContext},
         errors::UserInputError,
         models::Branch,
     },
     entry::Interactive,
-    template_config::{Template, TemplateConfig},
+    template_config::{CommitConfig, PromptConfig, TemplateConfig},
+    utils::string::OptionStr,
 };
 
 #[derive(Debug, Args, PartialEq, Eq, Clone)]
 #[group(skip)]
 pub struct Arguments {
-    /// Name of the commit template to be used.
+    /// Name or alias of the commit template to be used.
     pub template: Option<String>,
 
     /// Message for the commit.
@@ -33,33 +34,148 @@ impl Arguments {
         &self,
         config: &TemplateConfig,
         branch: &Option<Branch>,
+        default_template: Option<String>,
         prompter: &P,
         interactive: &Interactive,
     ) -> Result<Commit, UserInputError> {
-        let template = match &self.template {
-            Some(template) => template.into(),
-            None => Self::prompt_template_select(
-                config.commit.templates.clone(),
-                prompter,
-                interactive,
-            )?,
+        let template = match (&self.template, default_template) {
+            (Some(template), _) => template.into(),
+            (None, Some(default)) if interactive == &Interactive::Disable => default,
+            (None, default) => {
+                Self::prompt_template_select(&config.commit, default, prompter, interactive)?
+            }
+        };
+
+        let template = config.get_template_config(&template)?.clone();
+        config.validate_variables(Some(&template), &self.context.variables())?;
+
+        let (message, context) = if template.prompts.is_empty() {
+            let context = self
+                .context
+                .try_into_domain(prompter, interactive, branch)?;
+
+            (self.message.clone(), context)
+        } else {
+            self.try_prompt_declared(&template.prompts, branch, prompter, interactive)?
         };
 
-        let context = self
-            .context
-            .try_into_domain(prompter, interactive, branch)?;
+        let variables = config
+            .declared_variables(Some(&template))
+            .into_iter()
+            .map(|name| {
+                let value = context.variables.get(&name).cloned();
+                (name, value)
+            })
+            .collect();
 
         Ok(Commit {
-            template: config.get_template_config(&template)?.clone(),
-            message: self.message.clone(),
+            template,
+            message,
             ticket: context.ticket,
             scope: context.scope,
             link: context.link,
+            variables,
         })
     }
 
+    /// Ask only the questions declared by the template in order, skipping any provided as arguments.
+    fn try_prompt_declared<P: Prompter>(
+        &self,
+        prompts: &[PromptConfig],
+        branch: &Option<Branch>,
+        prompter: &P,
+        interactive: &Interactive,
+    ) -> Result<(Option<String>, DomainContext), UserInputError> {
+        let mut message = self.message.clone();
+        let mut context = DomainContext {
+            ticket: self.context.ticket.clone(),
+            scope: self.context.scope.clone(),
+            link: self.context.link.clone(),
+            variables: self.context.variables(),
+        };
+
+        for prompt in prompts {
+            let provided = match prompt.name.as_str() {
+                "message" => message.clone(),
+                "ticket_num" => context.ticket.clone(),
+                "scope" => context.scope.clone(),
+                "link" => context.link.clone(),
+                name => context.variables.get(name).cloned(),
+            };
+
+            let answer = match provided.none_if_empty() {
+                Some(answer) => {
+                    prompt.validate(&answer)?;
+                    Some(answer)
+                }
+                None => {
+                    let default = stored_value(branch, &prompt.name)
+                        .or_else(|| prompt.default.clone())
+                        .none_if_empty();
+
+                    match interactive {
+                        Interactive::Enable => Self::prompt_declared(prompt, default, prompter)?,
+                        Interactive::Disable => default,
+                    }
+                }
+            };
+
+            match prompt.name.as_str() {
+                "message" => message = answer,
+                "ticket_num" => context.ticket = answer,
+                "scope" => context.scope = answer,
+                "link" => context.link = answer,
+                name => {
+                    if let Some(answer) = answer {
+                        context.variables.insert(name.into(), answer);
+                    }
+                }
+            }
+        }
+
+        Ok((message, context))
+    }
+
+    fn prompt_declared<P: Prompter>(
+        prompt: &PromptConfig,
+        default: Option<String>,
+        prompter: &P,
+    ) -> Result<Option<String>, UserInputError> {
+        if prompt.choices.is_empty() {
+            return prompter.text_prompt(TextPrompt {
+                question: prompt.label(),
+                help: prompt.help.clone(),
+                default,
+                pattern: prompt.pattern()?,
+            });
+        }
+
+        // Offer the default choice first so it can simply be confirmed.
+        let mut choices = prompt.choices.clone();
+        let position = default.and_then(|default| choices.iter().position(|c| *c == default));
+        if let Some(position) = position {
+            let choice = choices.remove(position);
+            choices.insert(0, choice);
+        }
+
+        let items = choices
+            .into_iter()
+            .map(|choice| SelectItem {
+                name: choice.clone(),
+                value: choice,
+                description: None,
+                group: None,
+            })
+            .collect::<Vec<_>>();
+
+        let selected = prompter.select(&prompt.label(), items)?;
+
+        Ok(Some(selected.value))
+    }
+
     fn prompt_template_select<P: Prompter>(
-        templates: HashMap<String, Template>,
+        config: &CommitConfig,
+        default: Option<String>,
         prompter: &P,
         interactive: &Interactive,
     ) -> Result<String, UserInputError> {
@@ -69,30 +185,53 @@ impl Arguments {
             });
         }
 
-        let items = templates
+        let mut items = config
+            .ordered_templates()
             .into_iter()
             .map(|(name, template)| SelectItem {
                 name: name.clone(),
-                value: name,
-                description: Some(template.description),
+                value: name.clone(),
+                description: Some(template.description.clone()),
+                group: template.group.clone(),
             })
             .collect::<Vec<_>>();
 
+        // Offer the branch default first so it can simply be confirmed.
+        let default = default.and_then(|default| config.resolve(&default));
+        let position = default.and_then(|(name, _)| items.iter().position(|i| &i.name == name));
+        if let Some(position) = position {
+            let item = items.remove(position);
+            items.insert(0, item);
+        }
+
         let selected = prompter.select("Template", items)?;
 
         Ok(selected.name)
     }
 }
 
+fn stored_value(branch: &Option<Branch>, name: &str) -> Option<String> {
+    let branch = branch.as_ref()?;
+
+    match name {
+        "ticket_num" => Some(branch.ticket.clone()),
+        "scope" => branch.scope.clone(),
+        "link" => branch.link.clone(),
+        name => branch.variables.get(name).cloned(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use anyhow::Context;
     use fake::{Fake, Faker};
+    use indexmap::IndexMap;
+    use std::collections::{BTreeMap, HashMap};
 
     use crate::{
         domain::{adapters::prompt::SelectItem, errors::UserInputError},
-        template_config::CommitConfig,
+        template_config::{CommitConfig, Template},
     };
 
     #[test]
@@ -114,7 +253,7 @@ mod tests {
 
         let actual =
             args.clone()
-                .try_into_domain(&config, &None, &prompt, &Interactive::Disable)?;
+                .try_into_domain(&config, &None, None, &prompt, &Interactive::Disable)?;
 
         let expected = Commit {
             template: value,
@@ -122,6 +261,7 @@ mod tests {
             scope: args.context.scope.clone(),
             message: args.message.clone(),
             link: args.context.link,
+            variables: BTreeMap::new(),
         };
 
         assert_eq!(expected.template.content, actual.template.content);
@@ -145,6 +285,7 @@ mod tests {
                 ticket: None,
                 scope: None,
                 link: None,
+                variables: vec![],
             },
         };
 
@@ -157,9 +298,9 @@ mod tests {
             text_result: Ok(text_prompt.clone()),
         };
 
-        let actual = args
-            .clone()
-            .try_into_domain(&config, &None, &prompt, &Interactive::Enable)?;
+        let actual =
+            args.clone()
+                .try_into_domain(&config, &None, None, &prompt, &Interactive::Enable)?;
 
         let expected = Commit {
             template: value,
@@ -167,6 +308,7 @@ mod tests {
             scope: text_prompt.clone(),
             message: text_prompt.clone(),
             link: text_prompt.clone(),
+            variables: BTreeMap::new(),
         };
 
         assert_eq!(expected.template.description, actual.template.description);
@@ -192,8 +334,8 @@ mod tests {
                 ticket: Some(Faker.fake()),
                 scope: Some(Faker.fake()),
                 link: Some(Faker.fake()),
+                variables: vec![],
             },
-            ..fake_args()
         };
 
         let config = fake_template_config(Some((key.clone(), value.clone())));
@@ -203,9 +345,9 @@ mod tests {
             text_result: Err(anyhow::anyhow!("text should not be called")),
         };
 
-        let actual = args
-            .clone()
-            .try_into_domain(&config, &None, &prompt, &Interactive::Enable)?;
+        let actual =
+            args.clone()
+                .try_into_domain(&config, &None, None, &prompt, &Interactive::Enable)?;
 
         let expected = Commit {
             template: value,
@@ -213,6 +355,7 @@ mod tests {
             scope: args.context.scope.clone(),
             message: args.message.clone(),
             link: args.context.link,
+            variables: BTreeMap::new(),
         };
 
         assert_eq!(expected.template.description, actual.template.description);
@@ -241,12 +384,366 @@ mod tests {
 
         let error = args
             .clone()
-            .try_into_domain(&config, &None, &prompt, &Interactive::Disable)
+            .try_into_domain(&config, &None, None, &prompt, &Interactive::Disable)
             .unwrap_err();
 
         assert_eq!(error.to_string(), "Missing required \"template\" input");
     }
 
+    #[test]
+    fn try_into_domain_with_declared_variables_are_mapped_to_commit() -> anyhow::Result<()> {
+        let key = Faker.fake::<String>();
+
+        let args = Arguments {
+            template: Some(key.clone()),
+            context: context::Arguments {
+                variables: vec![("component".into(), "api".into())],
+                ..fake_args().context
+            },
+            ..fake_args()
+        };
+
+        let config = TemplateConfig {
+            variables: vec!["component".into(), "epic".into()],
+            ..fake_template_config(Some((key.clone(), fake_template(&key))))
+        };
+
+        let prompt = PromptTest {
+            select_item_name: Err(anyhow::anyhow!("select should not be called")),
+            text_result: Err(anyhow::anyhow!("text should not be called")),
+        };
+
+        let actual = args.try_into_domain(&config, &None, None, &prompt, &Interactive::Disable)?;
+
+        let expected = BTreeMap::from([
+            ("component".into(), Some("api".into())),
+            ("epic".into(), None),
+        ]);
+
+        assert_eq!(expected, actual.variables);
+
+        Ok(())
+    }
+
+    #[test]
+    fn try_into_domain_with_undeclared_variable_errors() {
+        let key = Faker.fake::<String>();
+
+        let args = Arguments {
+            template: Some(key.clone()),
+            context: context::Arguments {
+                variables: vec![("reviewer".into(), "sam".into())],
+                ..fake_args().context
+            },
+            ..fake_args()
+        };
+
+        let config = fake_template_config(Some((key.clone(), fake_template(&key))));
+
+        let prompt = PromptTest {
+            select_item_name: Err(anyhow::anyhow!("select should not be called")),
+            text_result: Err(anyhow::anyhow!("text should not be called")),
+        };
+
+        let error = args
+            .try_into_domain(&config, &None, None, &prompt, &Interactive::Disable)
+            .unwrap_err();
+
+        assert!(matches!(
+            error,
+            UserInputError::Validation { name, .. } if name == "reviewer"
+        ));
+    }
+
+    #[test]
+    fn try_into_domain_with_template_prompts_only_asks_declared_questions() -> anyhow::Result<()> {
+        let key = Faker.fake::<String>();
+
+        let template = Template {
+            prompts: vec![
+                fake_prompt("scope"),
+                PromptConfig {
+                    choices: vec!["api".into(), "cli".into()],
+                    ..fake_prompt("component")
+                },
+            ],
+            ..fake_template(&key)
+        };
+
+        let args = Arguments {
+            template: Some(key.clone()),
+            message: None,
+            context: context::Arguments {
+                ticket: None,
+                scope: None,
+                link: None,
+                variables: vec![],
+            },
+        };
+
+        let config = fake_template_config(Some((key, template)));
+
+        let prompt = PromptTest {
+            select_item_name: Ok("cli".into()),
+            text_result: Ok(Some("parser".into())),
+        };
+
+        let actual = args.try_into_domain(&config, &None, None, &prompt, &Interactive::Enable)?;
+
+        assert_eq!(actual.scope, Some("parser".into()));
+        assert_eq!(actual.ticket, None);
+        assert_eq!(actual.link, None);
+        assert_eq!(actual.message, None);
+        assert_eq!(
+            actual.variables,
+            BTreeMap::from([("component".into(), Some("cli".into()))])
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn try_into_domain_with_template_prompts_disabled_uses_defaults() -> anyhow::Result<()> {
+        let key = Faker.fake::<String>();
+
+        let template = Template {
+            prompts: vec![
+                PromptConfig {
+                    default: Some("core".into()),
+                    ..fake_prompt("scope")
+                },
+                PromptConfig {
+                    default: None,
+                    ..fake_prompt("ticket_num")
+                },
+            ],
+            ..fake_template(&key)
+        };
+
+        let args = Arguments {
+            template: Some(key.clone()),
+            context: context::Arguments {
+                ticket: Some("ABC-123".into()),
+                scope: None,
+                variables: vec![],
+                ..fake_args().context
+            },
+            ..fake_args()
+        };
+
+        let config = fake_template_config(Some((key, template)));
+
+        let prompt = PromptTest {
+            select_item_name: Err(anyhow::anyhow!("select should not be called")),
+            text_result: Err(anyhow::anyhow!("text should not be called")),
+        };
+
+        let actual = args.try_into_domain(&config, &None, None, &prompt, &Interactive::Disable)?;
+
+        assert_eq!(actual.scope, Some("core".into()));
+        assert_eq!(actual.ticket, Some("ABC-123".into()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn try_into_domain_with_template_prompts_validates_provided_arguments() {
+        let key = Faker.fake::<String>();
+
+        let template = Template {
+            prompts: vec![PromptConfig {
+                validate: Some("^[A-Z]+-[0-9]+$".into()),
+                ..fake_prompt("ticket_num")
+            }],
+            ..fake_template(&key)
+        };
+
+        let args = Arguments {
+            template: Some(key.clone()),
+            context: context::Arguments {
+                ticket: Some("abc".into()),
+                variables: vec![],
+                ..fake_args().context
+            },
+            ..fake_args()
+        };
+
+        let config = fake_template_config(Some((key, template)));
+
+        let prompt = PromptTest {
+            select_item_name: Err(anyhow::anyhow!("select should not be called")),
+            text_result: Err(anyhow::anyhow!("text should not be called")),
+        };
+
+        let error = args
+            .try_into_domain(&config, &None, None, &prompt, &Interactive::Enable)
+            .unwrap_err();
+
+        assert!(matches!(
+            error,
+            UserInputError::Validation { name, .. } if name == "ticket_num"
+        ));
+    }
+
+    #[test]
+    fn try_into_domain_with_template_prompt_answer_not_matching_pattern_errors() {
+        let key = Faker.fake::<String>();
+
+        let template = Template {
+            prompts: vec![PromptConfig {
+                validate: Some("^[a-z]+$".into()),
+                ..fake_prompt("scope")
+            }],
+            ..fake_template(&key)
+        };
+
+        let args = Arguments {
+            template: Some(key.clone()),
+            context: context::Arguments {
+                scope: None,
+                variables: vec![],
+                ..fake_args().context
+            },
+            ..fake_args()
+        };
+
+        let config = fake_template_config(Some((key, template)));
+
+        let prompt = PromptTest {
+            select_item_name: Err(anyhow::anyhow!("select should not be called")),
+            text_result: Ok(Some("Not Valid".into())),
+        };
+
+        let error = args
+            .try_into_domain(&config, &None, None, &prompt, &Interactive::Enable)
+            .unwrap_err();
+
+        assert!(matches!(error, UserInputError::Validation { .. }));
+    }
+
+    fn fake_prompt(name: &str) -> PromptConfig {
+        PromptConfig {
+            name: name.into(),
+            label: Faker.fake(),
+            help: Faker.fake(),
+            default: Faker.fake(),
+            validate: None,
+            choices: vec![],
+        }
+    }
+
+    #[test]
+    fn template_select_lists_templates_in_config_order_with_groups() -> anyhow::Result<()> {
+        let mut config = fake_template_config(None).commit;
+        config.templates.insert(
+            "option-0".into(),
+            Template {
+                group: Some("Fixes".into()),
+                order: Some(1),
+                ..fake_template("option-0")
+            },
+        );
+
+        let prompt = SelectRecorder::default();
+
+        Arguments::prompt_template_select(&config, None, &prompt, &Interactive::Enable)?;
+
+        assert_eq!(
+            prompt.items.take(),
+            vec![
+                ("option-0".to_string(), Some("Fixes".to_string())),
+                ("option-1".into(), None),
+                ("option-2".into(), None),
+                ("option-3".into(), None),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn branch_default_template_is_used_directly_when_prompts_are_disabled() -> anyhow::Result<()> {
+        let config = fake_template_config(Some(("bug".into(), fake_template("bug"))));
+
+        let args = Arguments {
+            template: None,
+            ..fake_args()
+        };
+
+        let prompt = PromptTest {
+            select_item_name: Err(anyhow::anyhow!("select should not be called")),
+            text_result: Err(anyhow::anyhow!("text should not be called")),
+        };
+
+        let actual = args.try_into_domain(
+            &config,
+            &None,
+            Some("bug".into()),
+            &prompt,
+            &Interactive::Disable,
+        )?;
+
+        assert_eq!(actual.template.description, "bug");
+
+        Ok(())
+    }
+
+    #[test]
+    fn branch_default_template_is_offered_first_within_the_select_prompt() -> anyhow::Result<()> {
+        let mut config = fake_template_config(None).commit;
+        config.templates.insert(
+            "bug".into(),
+            Template {
+                aliases: vec!["fix".into()],
+                ..fake_template("bug")
+            },
+        );
+
+        let prompt = SelectRecorder::default();
+
+        Arguments::prompt_template_select(
+            &config,
+            Some("fix".into()),
+            &prompt,
+            &Interactive::Enable,
+        )?;
+
+        let names = prompt
+            .items
+            .take()
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["bug", "option-1", "option-2", "option-3"]);
+
+        Ok(())
+    }
+
+    /// Records the listed select items choosing the first.
+    #[derive(Default)]
+    struct SelectRecorder {
+        items: std::cell::RefCell<Vec<(String, Option<String>)>>,
+    }
+
+    impl Prompter for SelectRecorder {
+        fn text(&self, _: &str, _: Option<String>) -> Result<Option<String>, UserInputError> {
+            panic!("Should not be called!")
+        }
+
+        fn select<T>(
+            &self,
+            _: &str,
+            options: Vec<SelectItem<T>>,
+        ) -> Result<SelectItem<T>, UserInputError> {
+            *self.items.borrow_mut() = options
+                .iter()
+                .map(|item| (item.name.clone(), item.group.clone()))
+                .collect();
+
+            Ok(options.into_iter().next().expect("Options to be listed"))
+        }
+    }
+
     pub struct PromptTest {
         select_item_name: anyhow::Result<String>,
         text_result: anyhow::Result<Option<String>>,
@@ -289,11 +786,17 @@ mod tests {
         Template {
             description: description.into(),
             content: Faker.fake(),
+            aliases: vec![],
+            header: true,
+            footer: true,
+            group: None,
+            order: None,
+            prompts: vec![],
         }
     }
 
     fn fake_template_config(selected: Option<(String, Template)>) -> TemplateConfig {
-        let mut map = HashMap::from([
+        let mut map = IndexMap::from([
             ("option-1".into(), fake_template("option-1")),
             ("option-2".into(), fake_template("option-2")),
             ("option-3".into(), fake_template("option-3")),
@@ -303,11 +806,19 @@ mod tests {
             map.insert(key, item);
         }
 
-        let config = CommitConfig { templates: map };
+        let config = CommitConfig {
+            header: None,
+            footer: None,
+            partials: HashMap::new(),
+            defaults: vec![],
+            templates: map,
+        };
 
         TemplateConfig {
             commit: config,
             version: 1,
+            extends: None,
+            variables: vec![],
             branch: None,
         }
     }
@@ -319,6 +830,7 @@ mod tests {
                 ticket: Faker.fake(),
                 scope: Faker.fake(),
                 link: Faker.fake(),
+                variables: vec![],
             },
             message: Faker.fake(),
         }
@@ -22,16 +22,49 @@ pub fn handler<G: Git, S: Store, P: Prompter>(
         Interactive::Disable => None,
     };
 
-    let templates = TemplateConfig::new(&context.config.path)?;
-    let commit = args
-        .try_into_domain(&templates, &branch, &prompter, &context.interactive)
+    let templates = TemplateConfig::load(&context.config, &context.store)?;
+    let default_template = match &args.template {
+        Some(_) => None,
+        None => get_default_template(context, &templates)?,
+    };
+
+    let mut commit = args
+        .try_into_domain(
+            &templates,
+            &branch,
+            default_template,
+            &prompter,
+            &context.interactive,
+        )
         .map_err(Errors::UserInput)?;
 
+    commit.template.content = templates
+        .commit
+        .content(&commit.template)
+        .map_err(Errors::Template)?;
+
     commit::handler(&context.git, branch, commit)?;
 
     Ok(())
 }
 
+/// Template matching the current branch name, the branch name is only looked up when defaults are configured.
+fn get_default_template<G: Git, S: Store>(
+    context: &AppContext<G, S>,
+    templates: &TemplateConfig,
+) -> Result<Option<String>, Errors> {
+    if templates.commit.defaults.is_empty() {
+        return Ok(None);
+    }
+
+    let branch_name = context.git.branch_name().map_err(Errors::Git)?;
+
+    templates
+        .commit
+        .default_template(branch_name.trim())
+        .map_err(Errors::UserInput)
+}
+
 fn get_branch<G: Git, S: Store>(context: &AppContext<G, S>) -> Result<Option<Branch>, Errors> {
     let branch_name = context.git.branch_name().map_err(Errors::Git)?;
     let repo_name = context.git.repository_name().map_err(Errors::Git)?;
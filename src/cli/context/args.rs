@@ -1,3 +1,5 @@
+use std::collections::BTreeMap;
+
 use clap::Args;
 
 use crate::{
@@ -22,6 +24,25 @@ pub struct Arguments {
     /// Issue ticket number link.
     #[clap(short, long, value_parser)]
     pub link: Option<String>,
+
+    /// Set a variable declared within the config i.e `--set component=api`.
+    #[clap(long = "set", value_name = "KEY=VALUE", value_parser = parse_variable)]
+    pub variables: Vec<(String, String)>,
+}
+
+fn parse_variable(value: &str) -> Result<(String, String), String> {
+    let (name, value) = value
+        .split_once('=')
+        .ok_or_else(|| format!("Expected 'KEY=VALUE' but found '{value}'"))?;
+
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(format!(
+            "Expected a variable name before '=' i.e 'component={value}'"
+        ));
+    }
+
+    Ok((name.into(), value.trim().into()))
 }
 
 impl Arguments {
@@ -49,6 +70,7 @@ impl Arguments {
             ticket,
             scope,
             link,
+            variables: self.variables(),
         })
     }
 
@@ -64,11 +86,16 @@ impl Arguments {
                 ticket: self.ticket.clone(),
                 scope: self.scope.clone(),
                 link: self.link.clone(),
+                variables: self.variables(),
             },
         };
 
         Ok(domain)
     }
+
+    pub fn variables(&self) -> BTreeMap<String, String> {
+        self.variables.iter().cloned().collect()
+    }
 }
 
 #[cfg(test)]
@@ -98,6 +125,7 @@ mod tests {
             ticket: args.ticket.clone(),
             scope: args.scope.clone(),
             link: args.link.clone(),
+            variables: args.variables(),
         };
 
         assert_eq!(expected, actual);
@@ -111,7 +139,7 @@ mod tests {
             ticket: None,
             scope: None,
             link: None,
-            ..fake_args()
+            variables: vec![],
         };
 
         let text_prompt = Faker.fake::<Option<String>>();
@@ -129,6 +157,7 @@ mod tests {
             ticket: text_prompt.clone(),
             scope: text_prompt.clone(),
             link: text_prompt.clone(),
+            variables: BTreeMap::new(),
         };
 
         assert_eq!(expected, actual);
@@ -143,6 +172,7 @@ mod tests {
             ticket: Some(Faker.fake()),
             scope: Some(Faker.fake()),
             link: Some(Faker.fake()),
+            variables: Faker.fake(),
         };
 
         let prompt = PromptTest {
@@ -158,6 +188,7 @@ mod tests {
             ticket: args.ticket.clone(),
             scope: args.scope.clone(),
             link: args.link.clone(),
+            variables: args.variables(),
         };
 
         assert_eq!(expected, actual);
@@ -189,7 +220,7 @@ mod tests {
             match &self.select_index {
                 Ok(index) => options
                     .into_iter()
-                    .nth(index.clone())
+                    .nth(*index)
                     .context("Failed to get item")
                     .map_err(|_| UserInputError::Validation {
                         name: name.into(),
@@ -203,11 +234,35 @@ mod tests {
         }
     }
 
+    #[test]
+    fn set_variables_are_parsed_as_key_value_pairs() {
+        assert_eq!(
+            parse_variable("component=api").unwrap(),
+            ("component".into(), "api".into())
+        );
+        assert_eq!(
+            parse_variable(" epic = EPIC-1=2 ").unwrap(),
+            ("epic".into(), "EPIC-1=2".into())
+        );
+        assert_eq!(
+            parse_variable("reviewer=").unwrap(),
+            ("reviewer".into(), "".into())
+        );
+    }
+
+    #[test]
+    fn set_variables_without_a_key_value_pair_errors() {
+        for value in ["component", "=api", " =api"] {
+            assert!(parse_variable(value).is_err(), "{value}");
+        }
+    }
+
     fn fake_args() -> Arguments {
         Arguments {
             ticket: Faker.fake(),
             scope: Faker.fake(),
             link: Faker.fake(),
+            variables: Faker.fake(),
         }
     }
 }
@@ -5,6 +5,7 @@ use crate::{
         commands::context,
         errors::Errors,
     },
+    template_config::TemplateConfig,
 };
 
 use super::Arguments;
@@ -22,6 +23,11 @@ pub fn handler<G: Git, S: Store, P: Prompter>(
         .try_into_domain(&prompt, &context.interactive, &branch)
         .map_err(Errors::UserInput)?;
 
+    let config = TemplateConfig::load(&context.config, &context.store)?;
+    config
+        .validate_variables(None, &args.variables)
+        .map_err(Errors::UserInput)?;
+
     context::handler(&context.git, &context.store, args)?;
 
     Ok(())
@@ -9,7 +9,10 @@ pub fn display_error(err: Errors) -> io::Result<()> {
         Errors::UserInput(inner) => print_user_error(inner),
         Errors::Git(inner) => print_error(inner.to_string(), None),
         Errors::PersistError(err) => print_persist_error(err),
-        Errors::Configuration { source, .. } => print_error(message, Some(source)),
+        Errors::Template(inner) => print_error(inner.to_string(), None),
+        Errors::Configuration { source, .. } => {
+            print_error(format!("{message}\n{source}"), Some(source))
+        }
         Errors::ValidationError { source, .. } => print_error(message, source),
     }
 }
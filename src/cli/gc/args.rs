@@ -0,0 +1,23 @@
+use clap::Args;
+
+use crate::domain::commands::gc::Gc;
+
+#[derive(Debug, Args, Clone)]
+pub struct Arguments {
+    /// List the branch contexts that would be removed without removing them.
+    #[clap(long, value_parser)]
+    pub dry_run: bool,
+
+    /// Only remove branch contexts created more than this many days ago.
+    #[clap(long, value_parser, value_name = "DAYS")]
+    pub older_than: Option<u32>,
+}
+
+impl From<Arguments> for Gc {
+    fn from(value: Arguments) -> Self {
+        Gc {
+            dry_run: value.dry_run,
+            older_than: value.older_than,
+        }
+    }
+}
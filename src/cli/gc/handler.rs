@@ -0,0 +1,47 @@
+use colored::Colorize;
+
+use crate::{
+    app_context::AppContext,
+    domain::{
+        adapters::{Git, Store},
+        commands::gc,
+        errors::Errors,
+    },
+};
+
+use super::Arguments;
+
+pub fn handler<G: Git, S: Store>(
+    context: &AppContext<G, S>,
+    args: Arguments,
+) -> Result<(), Errors> {
+    let dry_run = args.dry_run;
+
+    let removed = gc::handler(&context.git, &context.store, args.into())?;
+
+    for (name, branch) in &removed {
+        println!(
+            "🔴 {} ➜ {}",
+            name,
+            format!(
+                "ticket {} | created {}",
+                branch.ticket,
+                branch.created.format("%Y-%m-%d")
+            )
+            .italic()
+        );
+    }
+
+    match dry_run {
+        true => println!(
+            "{} branch context(s) would be removed",
+            removed.len().to_string().yellow()
+        ),
+        false => println!(
+            "🟢 Removed {} branch context(s)",
+            removed.len().to_string().green()
+        ),
+    }
+
+    Ok(())
+}
@@ -1,7 +1,9 @@
+pub mod branches;
 pub mod checkout;
 pub mod commands;
 pub mod commit;
 pub mod context;
 pub mod error;
+pub mod gc;
 pub mod log;
 pub mod template;
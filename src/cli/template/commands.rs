@@ -22,8 +22,22 @@ pub enum SubCommands {
     List,
     // List active templates.
     Active,
+    /// Print the resolved config in use along with the file each template came from.
+    Show,
     /// Reset to the default template.
     Reset,
+    /// Remove a registered template configuration.
+    Remove(TemplateRemove),
+    /// Rename a registered template configuration.
+    Rename(TemplateRename),
+    /// Check registered template configurations still exist & parse.
+    Verify,
+    /// Lint a config file reporting any problems found, defaults to the active config.
+    Lint(TemplateLint),
+    /// Rewrite a config file into the latest version layout keeping any comments.
+    Migrate(TemplateMigrate),
+    /// Print the JSON Schema of the config file layout for editor validation & autocompletion.
+    Schema,
 }
 
 #[derive(Debug, Args, PartialEq, Eq, Clone)]
@@ -34,6 +48,32 @@ pub struct TemplateAdd {
     pub path: String,
 }
 
+#[derive(Debug, Args, PartialEq, Eq, Clone)]
+pub struct TemplateRemove {
+    /// Name used to reference the config file.
+    pub name: String,
+}
+
+#[derive(Debug, Args, PartialEq, Eq, Clone)]
+pub struct TemplateRename {
+    /// Name used to reference the config file.
+    pub name: String,
+    /// New name used to reference the config file.
+    pub new_name: String,
+}
+
+#[derive(Debug, Args, PartialEq, Eq, Clone)]
+pub struct TemplateLint {
+    /// File path to the config file.
+    pub path: Option<String>,
+}
+
+#[derive(Debug, Args, PartialEq, Eq, Clone)]
+pub struct TemplateMigrate {
+    /// File path to the config file.
+    pub path: String,
+}
+
 #[derive(Debug, Args, PartialEq, Eq, Clone)]
 pub struct TemplateSet {
     /// Name used to reference the config file.
@@ -76,6 +116,7 @@ fn prompt_configuration_select<P: Prompter>(
             name: config.key.clone().into(),
             value: config.key.clone(),
             description: None,
+            group: None,
         })
         .collect();
 
@@ -1,12 +1,16 @@
 use crate::domain::adapters::prompt::Prompter;
 use crate::domain::adapters::Store;
-use crate::domain::errors::{Errors, UserInputError};
+use crate::domain::commands::{lint, migrate};
+use crate::domain::errors::{ConfigError, Errors, UserInputError};
 use crate::domain::models::path::{AbsolutePath, PathType};
 use crate::domain::models::{ConfigKey, Template, TemplateStatus};
 use crate::entry::Interactive;
-use crate::template_config::TemplateConfig;
+use crate::template_config::{ConfigFormat, ConfigLayer, TemplateConfig};
+use crate::utils::get_file_contents;
 
-use super::commands::{TemplateAdd, TemplateSet};
+use super::commands::{
+    TemplateAdd, TemplateLint, TemplateMigrate, TemplateRemove, TemplateRename, TemplateSet,
+};
 use super::SubCommands;
 use colored::Colorize;
 
@@ -23,25 +27,22 @@ pub fn handler<S: Store, P: Prompter>(
         SubCommands::Add(args) => add(args, store),
         SubCommands::Set(args) => set(args, store, prompt, interactive),
         SubCommands::Reset => reset(store),
+        SubCommands::Remove(args) => remove(args, store),
+        SubCommands::Rename(args) => rename(args, store),
+        SubCommands::Verify => verify(store),
         SubCommands::List => list(store),
-        SubCommands::Active => active_list(config),
+        SubCommands::Active => active_list(config, store),
+        SubCommands::Show => show(config, store),
+        SubCommands::Lint(args) => lint(args, config, store),
+        SubCommands::Migrate(args) => migrate(args),
+        SubCommands::Schema => schema(),
     }?;
 
     Ok(())
 }
 
 fn add<S: Store>(args: TemplateAdd, store: &mut S) -> Result<(), Errors> {
-    let key = ConfigKey::from(args.name.as_str());
-
-    if !key.is_overridable() {
-        return Err(Errors::UserInput(UserInputError::Validation {
-            name: "name".into(),
-            message: format!(
-                "Configuration '{}' cannot be overridden please choose another name",
-                args.name
-            ),
-        }));
-    }
+    let key = overridable_key("name", &args.name)?;
 
     let path = AbsolutePath::try_from(args.path, PathType::File)
         .map_err(|e| UserInputError::Validation {
@@ -99,6 +100,109 @@ fn reset<S: Store>(store: &mut S) -> Result<(), Errors> {
     Ok(())
 }
 
+fn remove<S: Store>(args: TemplateRemove, store: &mut S) -> Result<(), Errors> {
+    let key = overridable_key("name", &args.name)?;
+
+    let status = store.remove_template(&key).map_err(Errors::PersistError)?;
+
+    println!("🔴 {} removed", key.to_string().red());
+
+    // Fallback to the default so there is always an active config.
+    if status == TemplateStatus::Active {
+        reset(store)?;
+    }
+
+    Ok(())
+}
+
+fn rename<S: Store>(args: TemplateRename, store: &S) -> Result<(), Errors> {
+    let key = overridable_key("name", &args.name)?;
+    let new_key = overridable_key("new_name", &args.new_name)?;
+
+    let exists = store
+        .get_template_paths()
+        .map_err(Errors::PersistError)?
+        .into_iter()
+        .any(|(key, _)| key == new_key);
+
+    if exists {
+        return Err(Errors::UserInput(UserInputError::Validation {
+            name: "new_name".into(),
+            message: format!(
+                "Configuration '{}' already exists please choose another name",
+                args.new_name
+            ),
+        }));
+    }
+
+    store
+        .rename_template(&key, &new_key)
+        .map_err(Errors::PersistError)?;
+
+    println!("🟢 {} renamed to {}", key, new_key.to_string().green());
+
+    Ok(())
+}
+
+fn verify<S: Store>(store: &S) -> Result<(), Errors> {
+    let configurations = store.get_template_paths().map_err(Errors::PersistError)?;
+    let mut invalid = 0;
+
+    for (key, path) in configurations {
+        // Configs are loaded along with any they extend since a layer alone may be incomplete.
+        let problem = match AbsolutePath::try_from(path.clone(), PathType::File) {
+            Ok(path) => {
+                let config = Template {
+                    key: key.clone(),
+                    path,
+                    status: TemplateStatus::Disabled,
+                };
+
+                TemplateConfig::load(&config, store).err().map(|e| match e {
+                    Errors::Configuration { message, source } => format!("{message} {source}"),
+                    e => e.to_string(),
+                })
+            }
+            Err(e) => Some(e.to_string()),
+        };
+
+        match problem {
+            None => println!("🟢 {} ➜ '{}'", key.to_string().green(), path),
+            Some(problem) => {
+                invalid += 1;
+                println!("🔴 {} ➜ '{}' {}", key.to_string().red(), path, problem);
+            }
+        }
+    }
+
+    if invalid > 0 {
+        return Err(Errors::ValidationError {
+            message: format!(
+                "Found {invalid} invalid config(s) update or remove them with 'inkan config add' or 'inkan config remove'"
+            ),
+            source: None,
+        });
+    }
+
+    Ok(())
+}
+
+/// Built in configs are managed by inkan so can't be replaced, removed or renamed.
+fn overridable_key(input: &str, name: &str) -> Result<ConfigKey, Errors> {
+    let key = ConfigKey::from(name);
+
+    if !key.is_overridable() {
+        return Err(Errors::UserInput(UserInputError::Validation {
+            name: input.into(),
+            message: format!(
+                "Configuration '{name}' cannot be overridden please choose another name"
+            ),
+        }));
+    }
+
+    Ok(key)
+}
+
 fn list<S: Store>(store: &S) -> Result<(), Errors> {
     let mut configurations = store.get_templates().map_err(Errors::PersistError)?;
 
@@ -121,21 +225,210 @@ fn list<S: Store>(store: &S) -> Result<(), Errors> {
     Ok(())
 }
 
-pub fn active_list(config: &Template) -> Result<(), Errors> {
+pub fn active_list<S: Store>(config: &Template, store: &S) -> Result<(), Errors> {
     log::info!("collect commit templates from config.");
-    let templates = TemplateConfig::new(&config.path)?;
+    let templates = TemplateConfig::load(config, store)?;
 
-    let mut pairs = templates.commit.templates.into_iter().collect::<Vec<_>>();
+    let mut group = None;
 
-    pairs.sort_by_key(|(k, ..)| k.to_owned());
+    for (key, value) in templates.commit.ordered_templates() {
+        // Ungrouped templates listed after a group are separated by an empty line.
+        if value.group != group {
+            group = value.group.clone();
+            println!("{}", group.as_deref().unwrap_or_default().bold());
+        }
+
+        let aliases = match value.aliases.is_empty() {
+            true => String::new(),
+            false => format!(" ({})", value.aliases.join(", ")),
+        };
 
-    for (key, value) in pairs {
-        println!("- {} {}.", key.bold().green(), value.description.italic());
+        println!(
+            "- {}{} {}.",
+            key.bold().green(),
+            aliases.green(),
+            value.description.italic()
+        );
     }
 
     Ok(())
 }
 
+fn show<S: Store>(config: &Template, store: &S) -> Result<(), Errors> {
+    let layers = TemplateConfig::layers(config, store)?;
+    let parse_error = |e: ConfigError| Errors::Configuration {
+        message: format!("Failed to parse configuration at path '{}'", config.path),
+        source: e.into(),
+    };
+    let templates = TemplateConfig::merge(&layers).map_err(parse_error)?;
+
+    println!(
+        "🟢 {} ({}) ➜ '{}'",
+        config.key.to_string().green(),
+        config_reason(&config.key),
+        config.path
+    );
+
+    println!("\n{}", "Layers (merged in order):".bold());
+    for (index, layer) in layers.iter().enumerate() {
+        println!("{}. {} ➜ '{}'", index + 1, layer.key, layer.path);
+    }
+
+    if let Some(branch) = &templates.branch {
+        let sources = TemplateConfig::sources(&layers, &["branch"]).map_err(parse_error)?;
+        println!("\n{} {}", "Branch".bold(), provenance(&sources).italic());
+        println!("    {}", branch.content.trim_end());
+    }
+
+    println!("\n{}", "Templates:".bold());
+
+    for (key, template) in templates.commit.ordered_templates() {
+        let sources =
+            TemplateConfig::sources(&layers, &["commit", "templates", key]).map_err(parse_error)?;
+        let content = templates
+            .commit
+            .content(template)
+            .map_err(Errors::Template)?;
+
+        println!(
+            "- {} {} {}",
+            key.bold().green(),
+            template.description.italic(),
+            provenance(&sources).italic()
+        );
+
+        for line in content.trim_end().lines() {
+            println!("{}", format!("    {line}").trim_end());
+        }
+    }
+
+    Ok(())
+}
+
+/// Last layer declaring a value along with any layers it overrides i.e `➜ local (overrides conventional)`.
+fn provenance(sources: &[&ConfigLayer]) -> String {
+    match sources.split_last() {
+        Some((source, [])) => format!("➜ {} '{}'", source.key, source.path),
+        Some((source, overridden)) => format!(
+            "➜ {} '{}' (overrides {})",
+            source.key,
+            source.path,
+            overridden
+                .iter()
+                .map(|layer| layer.key.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        None => String::new(),
+    }
+}
+
+fn config_reason(config_key: &ConfigKey) -> &'static str {
+    match config_key {
+        ConfigKey::Once => "once off --config",
+        ConfigKey::Local => "local repository config file",
+        ConfigKey::User(_) => "active user config",
+        ConfigKey::Default | ConfigKey::Conventional => "active built-in config",
+    }
+}
+
+fn lint<S: Store>(args: TemplateLint, config: &Template, store: &S) -> Result<(), Errors> {
+    let config = match args.path {
+        Some(path) => Template {
+            key: ConfigKey::Once,
+            path: AbsolutePath::try_from(path, PathType::File)
+                .map_err(|e| UserInputError::Validation {
+                    name: "path".into(),
+                    message: e.to_string(),
+                })
+                .map_err(Errors::UserInput)?,
+            status: TemplateStatus::Active,
+        },
+        None => config.clone(),
+    };
+    let path = &config.path;
+
+    let problems = match TemplateConfig::layers(&config, store) {
+        Ok(layers) => lint::layers(&layers),
+        // Configs that fail to parse or extend are reported as a problem like any other.
+        Err(Errors::Configuration { message, source }) => vec![lint::Problem {
+            location: "config".into(),
+            message: format!("{message} {source}"),
+        }],
+        Err(e) => return Err(e),
+    };
+
+    if problems.is_empty() {
+        println!("🟢 {} no problems found", path.to_string().green());
+        return Ok(());
+    }
+
+    for problem in &problems {
+        println!("🔴 {} {}", problem.location.yellow(), problem.message);
+    }
+
+    Err(Errors::ValidationError {
+        message: format!("Found {} problem(s) in config '{}'", problems.len(), path),
+        source: None,
+    })
+}
+
+fn migrate(args: TemplateMigrate) -> Result<(), Errors> {
+    let path = AbsolutePath::try_from(args.path, PathType::File)
+        .map_err(|e| UserInputError::Validation {
+            name: "path".into(),
+            message: e.to_string(),
+        })
+        .map_err(Errors::UserInput)?;
+
+    let contents = get_file_contents(&path).map_err(|e| Errors::Configuration {
+        message: format!("Failed to read configuration at path '{}'", path),
+        source: e,
+    })?;
+
+    let format = ConfigFormat::from_path(path.as_ref());
+    let migration = migrate::handler(&contents, format).map_err(|e| Errors::Configuration {
+        message: format!("Failed to migrate configuration at path '{}'", path),
+        source: e.into(),
+    })?;
+
+    if migration.from == migration.to {
+        println!(
+            "🟢 {} already at the latest version {}",
+            path.to_string().green(),
+            migration.to
+        );
+        return Ok(());
+    }
+
+    std::fs::write(&path, migration.contents).map_err(|e| Errors::Configuration {
+        message: format!("Failed to write configuration at path '{}'", path),
+        source: e.into(),
+    })?;
+
+    println!(
+        "🟢 {} migrated from version {} to {}",
+        path.to_string().green(),
+        migration.from,
+        migration.to
+    );
+
+    Ok(())
+}
+
+fn schema() -> Result<(), Errors> {
+    let schema = serde_json::to_string_pretty(&TemplateConfig::schema()).map_err(|e| {
+        Errors::ValidationError {
+            message: "Failed to generate config schema".into(),
+            source: Some(e.into()),
+        }
+    })?;
+
+    println!("{schema}");
+
+    Ok(())
+}
+
 fn local_config_warning(config_key: &ConfigKey) {
     let warn_message = match config_key {
         ConfigKey::Once => Some("'once off' --config"),
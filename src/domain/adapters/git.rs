@@ -2,9 +2,12 @@ use std::path::{Path, PathBuf};
 
 use crate::domain::{errors::GitError, models::path::AbsolutePath};
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum CheckoutStatus {
-    New,
+    /// Create a new branch from the given ref i.e `main`, otherwise from the current `HEAD`.
+    New {
+        from: Option<String>,
+    },
     Existing,
 }
 
@@ -25,6 +28,18 @@ pub trait Git {
     /// Get the current checked out branch name.
     fn branch_name(&self) -> Result<String, GitError>;
 
+    /// Get the names of every local branch.
+    fn branches(&self) -> Result<Vec<String>, GitError>;
+
+    /// Get a git config value i.e `user.name`, `None` when it has not been set.
+    fn config_value(&self, key: &str) -> Result<Option<String>, GitError>;
+
+    /// Get the abbreviated commit hash of a revision i.e `HEAD`, `None` when it does not exist.
+    fn short_sha(&self, revision: &str) -> Result<Option<String>, GitError>;
+
+    /// Whether the working tree has any uncommitted or untracked changes.
+    fn is_dirty(&self) -> Result<bool, GitError>;
+
     /// Checkout an existing branch of create a new branch if not.
     fn checkout(&self, name: &str, status: CheckoutStatus) -> Result<(), GitError>;
 
@@ -1,9 +1,35 @@
+use regex::Regex;
+
 use crate::domain::errors::UserInputError;
 
 pub struct SelectItem<T> {
     pub name: String,
     pub value: T,
     pub description: Option<String>,
+    /// Section the item is listed under, items sharing a group are expected to be listed together.
+    pub group: Option<String>,
+}
+
+pub struct TextPrompt {
+    pub question: String,
+    pub help: Option<String>,
+    pub default: Option<String>,
+    /// Pattern any non empty answer must match.
+    pub pattern: Option<Regex>,
+}
+
+impl TextPrompt {
+    pub fn validate(&self, answer: &str) -> Result<(), UserInputError> {
+        match &self.pattern {
+            Some(pattern) if !answer.is_empty() && !pattern.is_match(answer) => {
+                Err(UserInputError::Validation {
+                    name: self.question.clone(),
+                    message: format!("Expected to match the pattern '{pattern}'"),
+                })
+            }
+            _ => Ok(()),
+        }
+    }
 }
 
 pub trait Prompter {
@@ -18,4 +44,15 @@ pub trait Prompter {
         question: &str,
         options: Vec<SelectItem<T>>,
     ) -> Result<SelectItem<T>, UserInputError>;
+
+    /// Text prompt with help and validation, by default the answer is only validated once given.
+    fn text_prompt(&self, prompt: TextPrompt) -> Result<Option<String>, UserInputError> {
+        let answer = self.text(&prompt.question, prompt.default.clone())?;
+
+        if let Some(answer) = &answer {
+            prompt.validate(answer)?;
+        }
+
+        Ok(answer)
+    }
 }
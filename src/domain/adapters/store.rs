@@ -1,6 +1,6 @@
 use crate::domain::{
     errors::PersistError,
-    models::{Branch, ConfigKey, Template},
+    models::{Branch, ConfigKey, Template, TemplateStatus},
 };
 
 pub trait Store {
@@ -8,6 +8,11 @@ pub trait Store {
 
     fn get_branch(&self, branch: &str, repo: &str) -> Result<Branch, PersistError>;
 
+    /// Every branch stored for the repository, most recently created first.
+    fn get_branches(&self, repo: &str) -> Result<Vec<Branch>, PersistError>;
+
+    fn delete_branch(&self, branch: &str, repo: &str) -> Result<(), PersistError>;
+
     fn persist_template(&self, config: &Template) -> Result<(), PersistError>;
 
     fn set_active_template(&mut self, key: &ConfigKey) -> Result<Template, PersistError>;
@@ -16,5 +21,13 @@ pub trait Store {
 
     fn get_template(&self, key: Option<String>) -> Result<Template, PersistError>;
 
+    /// Registered config keys along with the path as stored, without checking the path is still valid.
+    fn get_template_paths(&self) -> Result<Vec<(ConfigKey, String)>, PersistError>;
+
+    /// Removes the config returning the status it had so callers can tell if the active config was removed.
+    fn remove_template(&self, key: &ConfigKey) -> Result<TemplateStatus, PersistError>;
+
+    fn rename_template(&self, key: &ConfigKey, name: &ConfigKey) -> Result<(), PersistError>;
+
     fn close(self) -> anyhow::Result<()>;
 }
@@ -0,0 +1,42 @@
+use crate::domain::{
+    adapters::{Git, Store},
+    errors::Errors,
+    models::Branch,
+};
+
+/// Stored context for a branch within the current repository.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BranchContext {
+    /// Git branch name without the repository prefix i.e `feature/login`.
+    pub name: String,
+    /// Whether the git branch still exists locally.
+    pub exists: bool,
+    /// Whether the git branch is currently checked out.
+    pub current: bool,
+    pub branch: Branch,
+}
+
+/// Every stored branch context for the current repository, most recently created first.
+pub fn handler<G: Git, S: Store>(git: &G, store: &S) -> Result<Vec<BranchContext>, Errors> {
+    let repo_name = git.repository_name().map_err(Errors::Git)?;
+    let current = git.branch_name().map_err(Errors::Git)?;
+    let local_branches = git.branches().map_err(Errors::Git)?;
+
+    let branches = store
+        .get_branches(&repo_name)
+        .map_err(Errors::PersistError)?
+        .into_iter()
+        .map(|branch| {
+            let name = branch.branch_name().to_string();
+
+            BranchContext {
+                exists: local_branches.contains(&name),
+                current: name == current.trim(),
+                name,
+                branch,
+            }
+        })
+        .collect();
+
+    Ok(branches)
+}
@@ -1,13 +1,13 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use crate::{
     domain::{
         adapters::{CheckoutStatus, Git, Store},
-        errors::Errors,
+        errors::{Errors, GitError, UserInputError},
         models::Branch,
-        template::Templator,
+        template::Ast,
     },
-    template_config::TemplateConfig,
+    template_config::{BranchConfig, TemplateConfig},
 };
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -20,6 +20,12 @@ pub struct Checkout {
     pub scope: Option<String>,
     /// Issue ticket number link.
     pub link: Option<String>,
+    /// Type of branch selected from the configured branch types i.e `feature`.
+    pub branch_type: Option<String>,
+    /// Short title describing the changes i.e `Add login page`.
+    pub title: Option<String>,
+    /// Ref the new branch is created from i.e `main`, otherwise the current `HEAD`.
+    pub from: Option<String>,
 }
 
 impl From<Checkout> for HashMap<&str, Option<String>> {
@@ -28,11 +34,213 @@ impl From<Checkout> for HashMap<&str, Option<String>> {
             ("branch_name", Some(value.name)),
             ("ticket_num", value.ticket),
             ("scope", value.scope),
+            ("type", value.branch_type),
+            ("title", value.title),
         ])
     }
 }
 
-fn build_branch_name(args: &Checkout, template: &TemplateConfig) -> anyhow::Result<String> {
+const INVALID_CHARS: [char; 8] = [' ', '~', '^', ':', '?', '*', '[', '\\'];
+
+/// Branch name rewritten into a valid git ref.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BranchName {
+    pub name: String,
+    /// Each change made to the rendered name i.e `replaced ' ', ':' with '-'`.
+    pub changes: Vec<String>,
+}
+
+/// Checks the name against the git ref format rules, see `git check-ref-format`.
+pub fn validate_branch_name(name: &str) -> Result<(), String> {
+    let problem = if name.is_empty() {
+        Some("cannot be empty".into())
+    } else if let Some(c) = name
+        .chars()
+        .find(|c| c.is_ascii_control() || INVALID_CHARS.contains(c))
+    {
+        Some(format!("cannot contain {c:?}"))
+    } else if name.starts_with('-') {
+        Some("cannot start with '-'".into())
+    } else if name.starts_with('/') || name.ends_with('/') || name.contains("//") {
+        Some("cannot start or end with '/' or contain '//'".into())
+    } else if name.ends_with('.') || name.contains("..") {
+        Some("cannot end with '.' or contain '..'".into())
+    } else if name.contains("@{") || name == "@" {
+        Some("cannot contain '@{' or be '@'".into())
+    } else if name
+        .split('/')
+        .any(|part| part.starts_with('.') || part.ends_with(".lock"))
+    {
+        Some("path components cannot start with '.' or end with '.lock'".into())
+    } else {
+        None
+    };
+
+    match problem {
+        Some(problem) => Err(format!("Branch name {name:?} {problem}")),
+        None => Ok(()),
+    }
+}
+
+/// Rewrites the name to follow the git ref format rules, characters git does not allow are replaced with the separator.
+pub fn sanitize_branch_name(
+    name: &str,
+    separator: &str,
+    max_length: Option<usize>,
+) -> Result<BranchName, String> {
+    validate_branch_name(&format!("a{separator}b"))
+        .map_err(|_| format!("Branch separator {separator:?} is not valid within a git ref"))?;
+
+    let mut changes = vec![];
+
+    if name.trim() != name {
+        changes.push("removed leading & trailing whitespace".into());
+    }
+
+    let mut name = replace_invalid_chars(name.trim(), separator, &mut changes);
+
+    // Removing one rule can break another i.e `a.lock.` so repeat until nothing changes.
+    loop {
+        let cleaned = clean_branch_name(&name, &mut changes);
+
+        if cleaned == name {
+            break;
+        }
+
+        name = cleaned;
+    }
+
+    if let Some(max_length) = max_length.filter(|max| name.chars().count() > *max) {
+        changes.push(format!("truncated to {max_length} characters"));
+
+        name = name.chars().take(max_length).collect();
+        while !separator.is_empty() && name.ends_with(separator) {
+            name.truncate(name.len() - separator.len());
+        }
+        name = clean_branch_name(&name, &mut changes);
+    }
+
+    let mut reported = HashSet::new();
+    changes.retain(|change| reported.insert(change.clone()));
+
+    validate_branch_name(&name)?;
+
+    Ok(BranchName { name, changes })
+}
+
+fn replace_invalid_chars(name: &str, separator: &str, changes: &mut Vec<String>) -> String {
+    let mut replaced: Vec<char> = vec![];
+    let mut sanitized = String::new();
+    let mut previous_invalid = false;
+
+    for c in name.chars() {
+        let invalid = c.is_whitespace() || c.is_ascii_control() || INVALID_CHARS.contains(&c);
+
+        if invalid && !replaced.contains(&c) {
+            replaced.push(c);
+        }
+
+        // A run of invalid characters is replaced with a single separator.
+        match (invalid, previous_invalid) {
+            (true, false) => sanitized.push_str(separator),
+            (false, _) => sanitized.push(c),
+            (true, true) => {}
+        }
+
+        previous_invalid = invalid;
+    }
+
+    if replaced.is_empty() {
+        return sanitized;
+    }
+
+    // Avoid doubled up separators where one was already next to a replaced character i.e `fix - login`.
+    if !separator.is_empty() {
+        let doubled = separator.repeat(2);
+        while sanitized.contains(&doubled) {
+            sanitized = sanitized.replace(&doubled, separator);
+        }
+    }
+
+    let replaced: Vec<String> = replaced.iter().map(|c| format!("{c:?}")).collect();
+    changes.push(format!(
+        "replaced {} with {separator:?}",
+        replaced.join(", ")
+    ));
+
+    sanitized
+}
+
+fn clean_branch_name(name: &str, changes: &mut Vec<String>) -> String {
+    let mut name = name.to_string();
+
+    for (pattern, replacement) in [("..", "."), ("@{", "@")] {
+        if name.contains(pattern) {
+            changes.push(format!("replaced {pattern:?} with {replacement:?}"));
+            while name.contains(pattern) {
+                name = name.replace(pattern, replacement);
+            }
+        }
+    }
+
+    let components: Vec<&str> = name.split('/').collect();
+    let mut cleaned = vec![];
+
+    for component in &components {
+        let mut component = *component;
+
+        if component.starts_with('.') {
+            changes.push("removed leading '.' from path components".into());
+            component = component.trim_start_matches('.');
+        }
+
+        while let Some(stripped) = component.strip_suffix(".lock") {
+            changes.push("removed trailing \".lock\" from path components".into());
+            component = stripped;
+        }
+
+        if !component.is_empty() {
+            cleaned.push(component);
+        }
+    }
+
+    if cleaned.len() != components.len() {
+        changes.push("removed empty path components".into());
+    }
+
+    let mut name = cleaned.join("/");
+
+    if name.starts_with('-') {
+        changes.push("removed leading '-'".into());
+        name = name.trim_start_matches('-').into();
+    }
+
+    if name.ends_with('.') {
+        changes.push("removed trailing '.'".into());
+        name = name.trim_end_matches('.').into();
+    }
+
+    name
+}
+
+fn build_branch_name(args: &Checkout, template: &TemplateConfig) -> Result<BranchName, Errors> {
+    let (separator, max_length) = template
+        .branch
+        .as_ref()
+        .map(|branch| (branch.separator.as_str(), branch.max_length))
+        .unwrap_or((BranchConfig::DEFAULT_SEPARATOR, None));
+
+    let name = render_branch_name(args, template)?;
+
+    sanitize_branch_name(&name, separator, max_length).map_err(|message| {
+        Errors::UserInput(UserInputError::Validation {
+            name: "branch_name".into(),
+            message,
+        })
+    })
+}
+
+fn render_branch_name(args: &Checkout, template: &TemplateConfig) -> Result<String, Errors> {
     let args = args.clone();
 
     if template.branch.is_none() {
@@ -42,47 +250,194 @@ fn build_branch_name(args: &Checkout, template: &TemplateConfig) -> anyhow::Resu
     let branch_template = template
         .branch
         .as_ref()
-        .ok_or_else(|| anyhow::anyhow!("Expected valid template branch"))?;
+        .ok_or_else(|| Errors::ValidationError {
+            message: "Failed to build branch name from the specified config".into(),
+            source: Some(anyhow::anyhow!("Expected valid template branch")),
+        })?;
+
+    let ast = Ast::parse(&branch_template.content).map_err(Errors::Template)?;
+
+    let mut context = ast.environment();
+    context.extend(HashMap::from(args));
 
-    let contents = branch_template
-        .content
-        .replace_or_remove_pairs(args.into())?;
+    let contents = ast.render(&context).map_err(Errors::UserInput)?;
 
     Ok(contents)
 }
 
+/// Checks out the branch along with the name used, including any changes made to make it a valid git ref
+/// and whether the branch was created or already existed.
 pub fn handler<G: Git, S: Store>(
     git: &G,
     store: &S,
     template: TemplateConfig,
     args: Checkout,
-) -> Result<Branch, Errors> {
+) -> Result<(Branch, BranchName, CheckoutStatus), Errors> {
     // Build name
-    let name = build_branch_name(&args, &template).map_err(|e| Errors::ValidationError {
-        message: "Failed to build branch name from the specified config".into(),
-        source: Some(e),
-    })?;
+    let branch_name = build_branch_name(&args, &template)?;
+    let name = &branch_name.name;
+
+    let status = match &args.from {
+        Some(from) => checkout_from(git, name, from)?,
+        None => checkout(git, name)?,
+    };
+
+    // We want to store the branch name against and ticket number
+    // So whenever we commit we get the ticket number from the branch
+    let repo_name = git.repository_name().map_err(Errors::Git)?;
+
+    let branch = Branch::new(name, &repo_name, args.ticket, args.link, args.scope);
 
+    store
+        .persist_branch(&branch)
+        .map_err(Errors::PersistError)?;
+
+    Ok((branch, branch_name, status))
+}
+
+fn checkout<G: Git>(git: &G, name: &str) -> Result<CheckoutStatus, Errors> {
     // Attempt to create branch
-    let create = git.checkout(&name, CheckoutStatus::New);
+    let create = git.checkout(name, CheckoutStatus::New { from: None });
 
     // If the branch already exists check it out
     if let Err(err) = create {
         log::error!("failed to create new branch: {}", err);
 
-        git.checkout(&name, CheckoutStatus::Existing)
+        git.checkout(name, CheckoutStatus::Existing)
             .map_err(Errors::Git)?;
+
+        return Ok(CheckoutStatus::Existing);
     }
 
-    // We want to store the branch name against and ticket number
-    // So whenever we commit we get the ticket number from the branch
-    let repo_name = git.repository_name().map_err(Errors::Git)?;
+    Ok(CheckoutStatus::New { from: None })
+}
 
-    let branch = Branch::new(&name, &repo_name, args.ticket, args.link, args.scope);
+/// Existing branches are checked up front so an invalid ref isn't mistaken for the branch already existing.
+fn checkout_from<G: Git>(git: &G, name: &str, from: &str) -> Result<CheckoutStatus, Errors> {
+    if git
+        .branches()
+        .map_err(Errors::Git)?
+        .iter()
+        .any(|b| b == name)
+    {
+        git.checkout(name, CheckoutStatus::Existing)
+            .map_err(Errors::Git)?;
 
-    store
-        .persist_branch(&branch)
-        .map_err(Errors::PersistError)?;
+        return Ok(CheckoutStatus::Existing);
+    }
+
+    let status = CheckoutStatus::New {
+        from: Some(from.into()),
+    };
+
+    git.checkout(name, status.clone()).map_err(|err| {
+        log::error!("failed to create new branch: {}", err);
+
+        // The ref is only blamed once confirmed missing, otherwise i.e local changes would be overwritten.
+        match git.short_sha(from) {
+            Ok(Some(_)) => Errors::Git(err),
+            _ => Errors::Git(GitError::CheckoutFrom {
+                name: name.into(),
+                from: from.into(),
+            }),
+        }
+    })?;
+
+    Ok(status)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn valid_branch_names_pass() {
+        for name in ["feature/ABC-123-login", "fix_parser", "v1.0", "ABC-123"] {
+            assert!(validate_branch_name(name).is_ok(), "{name}");
+        }
+    }
+
+    #[test]
+    fn invalid_branch_names_fail() {
+        for name in [
+            "",
+            "has space",
+            "tilde~",
+            "caret^",
+            "colon:",
+            "question?",
+            "star*",
+            "[bracket",
+            "back\\slash",
+            "-dash",
+            "/slash",
+            "slash/",
+            "double//slash",
+            "dot.",
+            "double..dot",
+            "at@{",
+            "@",
+            ".hidden",
+            "path/.hidden",
+            "branch.lock",
+        ] {
+            assert!(validate_branch_name(name).is_err(), "{name}");
+        }
+    }
 
-    Ok(branch)
+    #[test]
+    fn valid_branch_names_are_left_as_is() {
+        let branch = sanitize_branch_name("feature/ABC-123-login", "-", None).unwrap();
+
+        assert_eq!(
+            branch,
+            BranchName {
+                name: "feature/ABC-123-login".into(),
+                changes: vec![]
+            }
+        );
+    }
+
+    #[test]
+    fn invalid_branch_names_are_sanitized() {
+        for (name, expected) in [
+            (" add login page ", "add-login-page"),
+            ("fix: login - page", "fix-login-page"),
+            ("what?*^~[x]\\y", "what-x]-y"),
+            ("-v1..0.", "v1.0"),
+            ("feature//.hidden/x.lock", "feature/hidden/x"),
+            ("release@{1}", "release@1}"),
+            ("a.lock.", "a"),
+        ] {
+            let branch = sanitize_branch_name(name, "-", None).unwrap();
+
+            assert_eq!(branch.name, expected, "{name}");
+            assert!(!branch.changes.is_empty(), "{name}");
+        }
+    }
+
+    #[test]
+    fn branch_names_are_truncated_to_max_length() {
+        let branch = sanitize_branch_name("ABC-123-add-login-page", "-", Some(12)).unwrap();
+
+        assert_eq!(branch.name, "ABC-123-add");
+        assert_eq!(branch.changes, vec!["truncated to 12 characters"]);
+    }
+
+    #[test]
+    fn branch_names_without_any_valid_characters_error() {
+        let error = sanitize_branch_name(" ~ ", "", None).unwrap_err();
+
+        assert_eq!(error, "Branch name \"\" cannot be empty");
+    }
+
+    #[test]
+    fn invalid_branch_separator_errors() {
+        let error = sanitize_branch_name("add login", ":", None).unwrap_err();
+
+        assert_eq!(
+            error,
+            "Branch separator \":\" is not valid within a git ref"
+        );
+    }
 }
@@ -1,5 +1,8 @@
+use std::collections::{BTreeMap, HashMap};
+
+use super::BuiltIns;
 use crate::{
-    domain::{models::Branch, template::Templator},
+    domain::{errors::Errors, models::Branch, template::Ast},
     template_config::Template,
     utils::string::OptionStr,
 };
@@ -11,6 +14,8 @@ pub struct Commit {
     pub message: Option<String>,
     pub scope: Option<String>,
     pub link: Option<String>,
+    /// User defined variables declared within the config with any value provided for this commit.
+    pub variables: BTreeMap<String, Option<String>>,
 }
 
 impl Commit {
@@ -18,11 +23,25 @@ impl Commit {
         &self,
         template: String,
         branch: Option<Branch>,
-    ) -> anyhow::Result<String> {
+        built_ins: &BuiltIns,
+    ) -> Result<String, Errors> {
         log::info!("generate commit message for '{}'", &template);
-        let (ticket, scope, link) = branch
-            .map(|branch| (Some(branch.ticket), branch.scope, branch.link))
-            .unwrap_or((None, None, None));
+        let (ticket, scope, link, mut variables) = branch
+            .map(|branch| {
+                let variables = branch
+                    .variables
+                    .into_iter()
+                    .map(|(name, value)| (name, Some(value)))
+                    .collect();
+
+                (Some(branch.ticket), branch.scope, branch.link, variables)
+            })
+            .unwrap_or((None, None, None, BTreeMap::new()));
+
+        for (name, value) in &self.variables {
+            let stored = variables.remove(name).flatten();
+            variables.insert(name.clone(), value.none_if_empty().or(stored));
+        }
 
         let ticket = self
             .ticket
@@ -32,11 +51,23 @@ impl Commit {
 
         let scope = self.scope.clone().none_if_empty().or(scope.none_if_empty());
 
-        let contents = template
-            .replace_or_remove("ticket_num", ticket)?
-            .replace_or_remove("scope", scope)?
-            .replace_or_remove("link", link)?
-            .replace_or_remove("message", self.message.clone())?;
+        let ast = Ast::parse(&template).map_err(Errors::Template)?;
+
+        let mut context: HashMap<&str, Option<String>> = variables
+            .iter()
+            .map(|(name, value)| (name.as_str(), value.clone()))
+            .collect();
+
+        context.extend(ast.environment());
+        context.extend(built_ins.context());
+        context.extend([
+            ("ticket_num", ticket),
+            ("scope", scope),
+            ("link", link),
+            ("message", self.message.clone()),
+        ]);
+
+        let contents = ast.render(&context).map_err(Errors::UserInput)?;
 
         Ok(contents)
     }
@@ -47,7 +78,10 @@ mod tests {
     use fake::{Fake, Faker};
 
     use super::*;
-    use crate::{domain::models::Branch, template_config::Template};
+    use crate::{
+        domain::{errors::UserInputError, models::Branch},
+        template_config::Template,
+    };
 
     #[derive(Clone)]
     struct TestCommand {
@@ -72,8 +106,9 @@ mod tests {
             ..fake_args()
         };
 
-        let actual = args.commit_message("{ticket_num} {message}".into(), None)?;
-        let expected = format!("{}", args.message.unwrap());
+        let actual =
+            args.commit_message("{ticket_num} {message}".into(), None, &BuiltIns::default())?;
+        let expected = args.message.unwrap().to_string();
 
         assert_eq!(actual, expected);
 
@@ -89,7 +124,11 @@ mod tests {
             ..fake_args()
         };
 
-        let actual = args.commit_message("({scope}) [{ticket_num}] {message}".into(), None)?;
+        let actual = args.commit_message(
+            "({scope}) [{ticket_num}] {message}".into(),
+            None,
+            &BuiltIns::default(),
+        )?;
         let expected = format!("[{}] {}", args.ticket.unwrap(), args.message.unwrap());
 
         assert_eq!(actual, expected);
@@ -106,8 +145,12 @@ mod tests {
                 ..fake_args()
             };
 
-            let actual = args.commit_message("[{ticket_num}] {message}".into(), None)?;
-            let expected = format!("{}", args.message.unwrap());
+            let actual = args.commit_message(
+                "[{ticket_num}] {message}".into(),
+                None,
+                &BuiltIns::default(),
+            )?;
+            let expected = args.message.unwrap().to_string();
 
             assert_eq!(actual, expected);
         }
@@ -120,6 +163,12 @@ mod tests {
         let template = Template {
             description: Faker.fake(),
             content: "[{ticket_num}] {message}".into(),
+            aliases: vec![],
+            header: true,
+            footer: true,
+            group: None,
+            order: None,
+            prompts: vec![],
         };
 
         let args = Commit {
@@ -129,7 +178,7 @@ mod tests {
             ..fake_args()
         };
 
-        let actual = args.commit_message(template.content.into(), None)?;
+        let actual = args.commit_message(template.content, None, &BuiltIns::default())?;
         let expected = format!("[{}] {}", args.ticket.unwrap(), args.message.unwrap());
 
         assert_eq!(actual, expected);
@@ -145,8 +194,9 @@ mod tests {
             ..fake_args()
         };
 
-        let actual = args.commit_message("{ticket_num} {message}".into(), None)?;
-        let expected = format!("{}", args.ticket.unwrap());
+        let actual =
+            args.commit_message("{ticket_num} {message}".into(), None, &BuiltIns::default())?;
+        let expected = args.ticket.unwrap().to_string();
 
         assert_eq!(expected.trim(), actual);
 
@@ -166,6 +216,7 @@ mod tests {
         let actual = args.commit_message(
             "fix({scope}): [{ticket_num}] {message}\n- done? [ ]".into(),
             None,
+            &BuiltIns::default(),
         )?;
         let expected = format!("fix: {}\n- done? [ ]", args.message.unwrap());
 
@@ -184,7 +235,11 @@ mod tests {
             ..fake_args()
         };
 
-        let actual = args.commit_message("[{ticket_num}] {message}".into(), Some(branch))?;
+        let actual = args.commit_message(
+            "[{ticket_num}] {message}".into(),
+            Some(branch),
+            &BuiltIns::default(),
+        )?;
         let expected = format!(
             "[{}] {}",
             &commands.branch_name,
@@ -196,16 +251,146 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn commit_template_footer_is_removed_without_a_ticket_num() -> anyhow::Result<()> {
+        let args = Commit {
+            ticket: None,
+            scope: None,
+            link: None,
+            message: Some(Faker.fake()),
+            ..fake_args()
+        };
+
+        let actual = args.commit_message(
+            "fix({scope}): {message}\n\n{#if ticket_num}Issue: {ticket_num} {link}{/if}".into(),
+            None,
+            &BuiltIns::default(),
+        )?;
+        let expected = format!("fix: {}", args.message.unwrap());
+
+        assert_eq!(expected, actual);
+
+        Ok(())
+    }
+
+    #[test]
+    fn commit_template_with_unclosed_section_errors() {
+        let args = fake_args();
+
+        let error = args
+            .commit_message(
+                "{#if ticket_num}Issue: {ticket_num}".into(),
+                None,
+                &BuiltIns::default(),
+            )
+            .unwrap_err();
+
+        assert!(error.to_string().contains("missing a closing"));
+    }
+
+    #[test]
+    fn commit_template_with_missing_required_ticket_num_errors() {
+        let args = Commit {
+            ticket: None,
+            ..fake_args()
+        };
+
+        let error = args
+            .commit_message(
+                "[{ticket_num!}] {message}".into(),
+                None,
+                &BuiltIns::default(),
+            )
+            .unwrap_err();
+
+        assert!(matches!(
+            error,
+            Errors::UserInput(UserInputError::Required { name }) if name == "ticket_num"
+        ));
+    }
+
+    #[test]
+    fn commit_template_variables_fallback_to_stored_branch_variables() -> anyhow::Result<()> {
+        let commands = TestCommand::fake();
+
+        let branch = Branch {
+            variables: BTreeMap::from([
+                ("component".into(), "api".into()),
+                ("epic".into(), "EPIC-1".into()),
+            ]),
+            ..Branch::new(&commands.branch_name, &commands.repo, None, None, None)
+        };
+
+        let args = Commit {
+            message: Some("msg".into()),
+            variables: BTreeMap::from([
+                ("component".into(), Some("cli".into())),
+                ("epic".into(), None),
+                ("reviewer".into(), None),
+            ]),
+            ..fake_args()
+        };
+
+        let actual = args.commit_message(
+            "{message} ({component}) [{epic}] reviewed by: {reviewer}".into(),
+            Some(branch),
+            &BuiltIns::default(),
+        )?;
+
+        assert_eq!(actual, "msg (cli) [EPIC-1] reviewed by:");
+
+        Ok(())
+    }
+
+    #[test]
+    fn commit_template_built_ins_and_environment_variables_are_injected() -> anyhow::Result<()> {
+        std::env::set_var("INKAN_COMMIT_TEST_RELEASE", "1.2.0");
+
+        let built_ins = BuiltIns {
+            branch_name: Some("release/1.2".into()),
+            repo: Some("inkan".into()),
+            author_name: Some("Jane Doe".into()),
+            author_email: Some("jane@doe.com".into()),
+            date: Some("2023-01-31".into()),
+            short_sha_parent: None,
+        };
+
+        let args = Commit {
+            message: Some("msg".into()),
+            ..fake_args()
+        };
+
+        let actual = args.commit_message(
+            "{message}\n\n{repo} {env.INKAN_COMMIT_TEST_RELEASE} ({branch_name}) {date}\n[{short_sha_parent}] {author_name} <{author_email}>".into(),
+            None,
+            &built_ins,
+        )?;
+
+        assert_eq!(
+            actual,
+            "msg\n\ninkan 1.2.0 (release/1.2) 2023-01-31\nJane Doe <jane@doe.com>"
+        );
+
+        Ok(())
+    }
+
     fn fake_args() -> Commit {
         Commit {
             template: Template {
                 description: Faker.fake(),
                 content: Faker.fake(),
+                aliases: vec![],
+                header: true,
+                footer: true,
+                group: None,
+                order: None,
+                prompts: vec![],
             },
             ticket: Faker.fake(),
             message: Faker.fake(),
             scope: Faker.fake(),
             link: Faker.fake(),
+            variables: BTreeMap::new(),
         }
     }
 }
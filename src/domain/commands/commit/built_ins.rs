@@ -0,0 +1,208 @@
+use chrono::Local;
+
+use crate::domain::{adapters::Git, errors::GitError};
+
+/// Values derived from git available to every commit template.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BuiltIns {
+    /// Current checked out branch name.
+    pub branch_name: Option<String>,
+    /// Current git repository name.
+    pub repo: Option<String>,
+    /// Configured git `user.name`.
+    pub author_name: Option<String>,
+    /// Configured git `user.email`.
+    pub author_email: Option<String>,
+    /// Todays date i.e `2023-01-31`.
+    pub date: Option<String>,
+    /// Abbreviated hash of the commit the new commit will be made on top of.
+    pub short_sha_parent: Option<String>,
+}
+
+impl BuiltIns {
+    /// Resolve the values used by the template from git, any that fail to resolve are left empty so
+    /// the template cleanup rules can remove them i.e a new repo has no parent commit.
+    ///
+    /// Values the template does not use are left empty to avoid running git commands for nothing.
+    pub fn resolve<G: Git>(git: &G, names: &[&str]) -> BuiltIns {
+        let used = |name: &str| names.contains(&name);
+
+        BuiltIns {
+            branch_name: used("branch_name")
+                .then(|| ok_or_log(git.branch_name().map(|name| Some(name.trim().into()))))
+                .flatten(),
+            repo: used("repo")
+                .then(|| ok_or_log(git.repository_name().map(Some)))
+                .flatten(),
+            author_name: used("author_name")
+                .then(|| ok_or_log(git.config_value("user.name")))
+                .flatten(),
+            author_email: used("author_email")
+                .then(|| ok_or_log(git.config_value("user.email")))
+                .flatten(),
+            date: used("date").then(|| Local::now().format("%Y-%m-%d").to_string()),
+            short_sha_parent: used("short_sha_parent")
+                .then(|| ok_or_log(git.short_sha("HEAD")))
+                .flatten(),
+        }
+    }
+
+    pub fn context(&self) -> [(&'static str, Option<String>); 6] {
+        [
+            ("branch_name", self.branch_name.clone()),
+            ("repo", self.repo.clone()),
+            ("author_name", self.author_name.clone()),
+            ("author_email", self.author_email.clone()),
+            ("date", self.date.clone()),
+            ("short_sha_parent", self.short_sha_parent.clone()),
+        ]
+    }
+}
+
+fn ok_or_log(result: Result<Option<String>, GitError>) -> Option<String> {
+    result
+        .map_err(|e| log::warn!("failed to resolve built in template value: {}", e))
+        .ok()
+        .flatten()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::{Path, PathBuf};
+
+    use fake::{Fake, Faker};
+
+    use super::*;
+    use crate::domain::{
+        adapters::{CheckoutStatus, CommitMsgStatus},
+        models::path::AbsolutePath,
+    };
+
+    struct GitMock {
+        branch_name: Result<String, ()>,
+        repo: String,
+        config_value: fn(&str) -> Option<String>,
+        short_sha: Option<String>,
+    }
+
+    impl Git for GitMock {
+        fn root_directory(&self) -> Result<AbsolutePath, GitError> {
+            panic!("Should not be called!")
+        }
+
+        fn repository_name(&self) -> Result<String, GitError> {
+            Ok(self.repo.clone())
+        }
+
+        fn branch_name(&self) -> Result<String, GitError> {
+            self.branch_name.clone().map_err(|_| GitError::BranchName)
+        }
+
+        fn config_value(&self, key: &str) -> Result<Option<String>, GitError> {
+            Ok((self.config_value)(key))
+        }
+
+        fn short_sha(&self, revision: &str) -> Result<Option<String>, GitError> {
+            assert_eq!(revision, "HEAD");
+            Ok(self.short_sha.clone())
+        }
+
+        fn branches(&self) -> Result<Vec<String>, GitError> {
+            panic!("Should not be called!")
+        }
+
+        fn is_dirty(&self) -> Result<bool, GitError> {
+            panic!("Should not be called!")
+        }
+
+        fn checkout(&self, _: &str, _: CheckoutStatus) -> Result<(), GitError> {
+            panic!("Should not be called!")
+        }
+
+        fn template_file_path(&self) -> Result<PathBuf, GitError> {
+            panic!("Should not be called!")
+        }
+
+        fn commit_with_template(&self, _: &Path, _: CommitMsgStatus) -> Result<(), GitError> {
+            panic!("Should not be called!")
+        }
+    }
+
+    const ALL: [&str; 6] = [
+        "branch_name",
+        "repo",
+        "author_name",
+        "author_email",
+        "date",
+        "short_sha_parent",
+    ];
+
+    #[test]
+    fn built_ins_not_used_by_the_template_are_not_resolved() {
+        let git = GitMock {
+            branch_name: Ok("main".into()),
+            repo: Faker.fake(),
+            config_value: |key| match key {
+                "user.email" => Some("jane@doe.com".into()),
+                _ => panic!("Unexpected config key {key}"),
+            },
+            short_sha: None,
+        };
+
+        let built_ins = BuiltIns::resolve(&git, &["author_email", "message"]);
+
+        assert_eq!(
+            built_ins,
+            BuiltIns {
+                author_email: Some("jane@doe.com".into()),
+                ..BuiltIns::default()
+            }
+        );
+    }
+
+    #[test]
+    fn built_ins_are_resolved_from_git() {
+        let git = GitMock {
+            branch_name: Ok("release/1.0\n".into()),
+            repo: Faker.fake(),
+            config_value: |key| match key {
+                "user.name" => Some("Jane Doe".into()),
+                "user.email" => Some("jane@doe.com".into()),
+                _ => panic!("Unexpected config key {key}"),
+            },
+            short_sha: Some("a1b2c3d".into()),
+        };
+
+        let built_ins = BuiltIns::resolve(&git, &ALL);
+
+        assert_eq!(
+            built_ins,
+            BuiltIns {
+                branch_name: Some("release/1.0".into()),
+                repo: Some(git.repo),
+                author_name: Some("Jane Doe".into()),
+                author_email: Some("jane@doe.com".into()),
+                date: Some(Local::now().format("%Y-%m-%d").to_string()),
+                short_sha_parent: Some("a1b2c3d".into()),
+            }
+        );
+    }
+
+    #[test]
+    fn built_ins_that_fail_to_resolve_are_left_empty() {
+        let git = GitMock {
+            branch_name: Err(()),
+            repo: Faker.fake(),
+            config_value: |_| None,
+            short_sha: None,
+        };
+
+        let built_ins = BuiltIns::resolve(&git, &ALL);
+
+        assert_eq!(built_ins.branch_name, None);
+        assert_eq!(built_ins.author_name, None);
+        assert_eq!(built_ins.author_email, None);
+        assert_eq!(built_ins.short_sha_parent, None);
+        assert!(built_ins.date.is_some());
+    }
+}
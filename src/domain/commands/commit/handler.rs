@@ -3,19 +3,17 @@ use crate::{
         adapters::{CommitMsgStatus, Git},
         errors::Errors,
         models::Branch,
+        template::Ast,
     },
     utils::string::OptionStr,
 };
 
-use super::Commit;
+use super::{BuiltIns, Commit};
 
 pub fn handler<G: Git>(git: &G, branch: Option<Branch>, commit: Commit) -> Result<String, Errors> {
-    let contents = commit
-        .commit_message(commit.template.content.clone(), branch)
-        .map_err(|e| Errors::Configuration {
-            message: "Failed attempting to build commit message".into(),
-            source: e,
-        })?;
+    let ast = Ast::parse(&commit.template.content).map_err(Errors::Template)?;
+    let built_ins = BuiltIns::resolve(git, &ast.names());
+    let contents = commit.commit_message(commit.template.content.clone(), branch, &built_ins)?;
 
     let template_file = git.template_file_path().map_err(Errors::Git)?;
 
@@ -1,5 +1,7 @@
 mod args;
+mod built_ins;
 mod handler;
 
 pub use args::Commit;
+pub use built_ins::BuiltIns;
 pub use handler::handler;
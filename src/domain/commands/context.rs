@@ -1,7 +1,12 @@
-use crate::domain::{
-    adapters::{Git, Store},
-    errors::Errors,
-    models::Branch,
+use std::collections::BTreeMap;
+
+use crate::{
+    domain::{
+        adapters::{Git, Store},
+        errors::{Errors, PersistError},
+        models::Branch,
+    },
+    utils::string::OptionStr,
 };
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -12,6 +17,8 @@ pub struct Context {
     pub scope: Option<String>,
     /// Issue ticket number link.
     pub link: Option<String>,
+    /// User defined variables to set, an empty value removes the variable.
+    pub variables: BTreeMap<String, String>,
 }
 
 pub fn handler<G: Git, S: Store>(git: &G, store: &S, args: Context) -> Result<Branch, Errors> {
@@ -21,7 +28,31 @@ pub fn handler<G: Git, S: Store>(git: &G, store: &S, args: Context) -> Result<Br
 
     let branch_name = git.branch_name().map_err(Errors::Git)?;
 
-    let branch = Branch::new(&branch_name, &repo_name, args.ticket, args.link, args.scope);
+    let existing = match store.get_branch(&branch_name, &repo_name) {
+        Ok(branch) => Some(branch),
+        Err(PersistError::NotFound { .. }) => None,
+        Err(e) => return Err(Errors::PersistError(e)),
+    };
+
+    let mut branch = match existing {
+        // Keep any existing context for values that have not been provided.
+        Some(existing) => Branch {
+            ticket: args.ticket.unwrap_or(existing.ticket),
+            link: args.link.or(existing.link),
+            scope: args.scope.or(existing.scope),
+            ..existing
+        },
+        None => Branch::new(&branch_name, &repo_name, args.ticket, args.link, args.scope),
+    };
+
+    // Merge with any existing variables so setting one does not clear the others.
+    for (name, value) in args.variables {
+        match value.none_if_empty() {
+            Some(value) => branch.variables.insert(name, value),
+            None => branch.variables.remove(&name),
+        };
+    }
+
     store
         .persist_branch(&branch)
         .map_err(Errors::PersistError)?;
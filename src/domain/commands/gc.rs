@@ -0,0 +1,60 @@
+use chrono::{Duration, Utc};
+
+use crate::domain::{
+    adapters::{Git, Store},
+    errors::Errors,
+    models::Branch,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Gc {
+    /// List the branch contexts that would be removed without removing them.
+    pub dry_run: bool,
+    /// Only remove branch contexts created more than this many days ago.
+    pub older_than: Option<u32>,
+}
+
+/// Removes stored branch contexts for the current repository whose git branch no longer exists locally,
+/// returning the git branch names along with the contexts removed.
+///
+/// Other repositories are left alone as their local branches can't be checked without knowing where they live.
+pub fn handler<G: Git, S: Store>(
+    git: &G,
+    store: &S,
+    args: Gc,
+) -> Result<Vec<(String, Branch)>, Errors> {
+    let repo_name = git.repository_name().map_err(Errors::Git)?;
+    let local_branches = git.branches().map_err(Errors::Git)?;
+
+    // A repository without any commits has no branches yet, rather than treating every context as orphaned.
+    if local_branches.is_empty() {
+        return Err(Errors::ValidationError {
+            message: "No local git branches found to compare stored branches against".into(),
+            source: None,
+        });
+    }
+
+    let cutoff = Utc::now() - Duration::days(args.older_than.unwrap_or_default().into());
+
+    let orphaned: Vec<(String, Branch)> = store
+        .get_branches(&repo_name)
+        .map_err(Errors::PersistError)?
+        .into_iter()
+        .map(|branch| (branch.branch_name().to_string(), branch))
+        .filter(|(name, branch)| !local_branches.contains(name) && branch.created <= cutoff)
+        .collect();
+
+    if args.dry_run {
+        return Ok(orphaned);
+    }
+
+    for (name, _) in &orphaned {
+        log::info!("removing orphaned branch context '{}'", name);
+
+        store
+            .delete_branch(name, &repo_name)
+            .map_err(Errors::PersistError)?;
+    }
+
+    Ok(orphaned)
+}
@@ -0,0 +1,683 @@
+use std::collections::HashMap;
+
+use crate::{
+    domain::{
+        commands::checkout::sanitize_branch_name,
+        template::{Ast, ENV_PREFIX},
+    },
+    template_config::{
+        BranchConfig, ConfigFormat, ConfigLayer, Template, TemplateConfig, BUILT_IN_VARIABLES,
+        SUPPORTED_VERSIONS,
+    },
+};
+
+/// Context variables available to branch templates.
+const BRANCH_VARIABLES: [&str; 5] = ["branch_name", "ticket_num", "scope", "type", "title"];
+
+/// Problem found within a config file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Problem {
+    /// Section of the config the problem was found in i.e `commit.templates.fix`.
+    pub location: String,
+    pub message: String,
+}
+
+impl Problem {
+    fn new<L: Into<String>, M: Into<String>>(location: L, message: M) -> Problem {
+        Problem {
+            location: location.into(),
+            message: message.into(),
+        }
+    }
+}
+
+/// Lint the raw config contents reporting any problems found.
+pub fn handler(contents: &str, format: ConfigFormat) -> Vec<Problem> {
+    // Parsing into a generic value first catches duplicate keys, which are otherwise silently replaced.
+    let raw = match format.deserialize::<serde_yaml::Value>(contents) {
+        Ok(raw) => raw,
+        Err(e) => return vec![Problem::new("config", e.to_string())],
+    };
+
+    let config = match format.deserialize::<TemplateConfig>(contents) {
+        Ok(config) => config,
+        Err(e) => return vec![Problem::new("config", e.to_string())],
+    };
+
+    let mut problems = vec![];
+
+    if config.rejects_unknown_keys() {
+        problems.extend(
+            config
+                .unknown_keys(&raw)
+                .into_iter()
+                .map(|key| Problem::new(key, "Unknown key")),
+        );
+    }
+
+    problems.extend(lint(&config));
+
+    problems
+}
+
+/// Lint a config merged on top of the configs it extends, a config only containing overrides isn't valid on its own.
+pub fn layers(layers: &[ConfigLayer]) -> Vec<Problem> {
+    match layers {
+        [layer] => handler(&layer.contents, layer.format),
+        layers => match TemplateConfig::merge(layers) {
+            Ok(config) => lint(&config),
+            Err(e) => vec![Problem::new("config", e.to_string())],
+        },
+    }
+}
+
+pub fn lint(config: &TemplateConfig) -> Vec<Problem> {
+    let mut problems = vec![];
+
+    if !SUPPORTED_VERSIONS.contains(&config.version) {
+        problems.push(Problem::new(
+            "version",
+            format!(
+                "Unsupported version {} expected one of {:?}",
+                config.version, SUPPORTED_VERSIONS
+            ),
+        ));
+    }
+
+    if let Some(branch) = &config.branch {
+        problems.extend(lint_content("branch", &branch.content, &BRANCH_VARIABLES));
+        match sanitize_branch_name("branch name", &branch.separator, None) {
+            Ok(_) => problems.extend(lint_branch_ref(branch)),
+            Err(message) => problems.push(Problem::new("branch.separator", message)),
+        }
+    }
+
+    for name in config.reserved_variables() {
+        problems.push(Problem::new(
+            "variables",
+            format!("Variable {name:?} is provided by inkan and cannot be declared"),
+        ));
+    }
+
+    let mut templates = config.commit.templates.iter().collect::<Vec<_>>();
+    templates.sort_by_key(|(name, _)| name.to_owned());
+
+    for (name, template) in templates {
+        let location = format!("commit.templates.{name}");
+        let declared = config.declared_variables(Some(template));
+        let known = BUILT_IN_VARIABLES
+            .iter()
+            .copied()
+            .chain(declared.iter().map(String::as_str))
+            .collect::<Vec<_>>();
+
+        match config.commit.content(template) {
+            Ok(content) => problems.extend(lint_content(&location, &content, &known)),
+            Err(e) => problems.push(Problem::new(&location, e.to_string())),
+        }
+
+        problems.extend(lint_prompts(&location, template));
+    }
+
+    problems.extend(lint_defaults(config));
+
+    for (name, templates) in config.alias_collisions() {
+        problems.push(Problem::new(
+            "commit.templates",
+            format!(
+                "Name or alias \"{name}\" is claimed by more than one template [{}]",
+                templates.join(", ")
+            ),
+        ));
+    }
+
+    problems
+}
+
+fn lint_content(location: &str, content: &str, known: &[&str]) -> Vec<Problem> {
+    if content.trim().is_empty() {
+        return vec![Problem::new(location, "Template content is empty")];
+    }
+
+    let mut problems = vec![];
+
+    if !braces_balanced(content) {
+        problems.push(Problem::new(location, "Unbalanced braces found"));
+    }
+
+    let ast = match Ast::parse(content) {
+        Ok(ast) => ast,
+        Err(e) => {
+            problems.push(Problem::new(location, e.to_string()));
+            return problems;
+        }
+    };
+
+    for name in ast.names() {
+        if known.contains(&name) || name.starts_with(ENV_PREFIX) {
+            continue;
+        }
+
+        let message = match closest(name, known) {
+            Some(suggestion) => format!("Unknown placeholder {name:?} did you mean {suggestion:?}"),
+            None => format!("Unknown placeholder {name:?}"),
+        };
+
+        problems.push(Problem::new(location, message));
+    }
+
+    problems
+}
+
+/// Checkout sanitizes the rendered name so only names still invalid afterwards are reported i.e an empty name.
+fn lint_branch_ref(branch: &BranchConfig) -> Option<Problem> {
+    let context: HashMap<&str, Option<String>> = HashMap::from([
+        ("branch_name", Some("branch-name".into())),
+        ("ticket_num", Some("ABC-123".into())),
+        ("scope", Some("scope".into())),
+        ("type", Some("feature".into())),
+        ("title", Some("Add login page".into())),
+    ]);
+
+    let name = Ast::parse(&branch.content).ok()?.render(&context).ok()?;
+
+    sanitize_branch_name(&name, &branch.separator, branch.max_length)
+        .err()
+        .map(|message| Problem::new("branch", format!("Invalid git ref, {message}")))
+}
+
+fn lint_prompts(location: &str, template: &Template) -> Vec<Problem> {
+    template
+        .prompts
+        .iter()
+        .filter_map(|prompt| prompt.pattern().err())
+        .map(|e| Problem::new(format!("{location}.prompts"), e.to_string()))
+        .collect()
+}
+
+fn lint_defaults(config: &TemplateConfig) -> Vec<Problem> {
+    let mut problems = vec![];
+
+    for (index, default) in config.commit.defaults.iter().enumerate() {
+        let location = format!("commit.defaults[{index}]");
+
+        if let Err(e) = default.pattern() {
+            problems.push(Problem::new(&location, e.to_string()));
+        }
+
+        if config.commit.resolve(&default.template).is_none() {
+            problems.push(Problem::new(
+                &location,
+                format!("Unknown template {:?}", default.template),
+            ));
+        }
+    }
+
+    problems
+}
+
+fn braces_balanced(content: &str) -> bool {
+    let mut depth = 0;
+    let mut chars = content.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            // Escaped braces are literal text so never need to be balanced.
+            '\\' => {
+                chars.next_if(|next| matches!(next, '{' | '}'));
+            }
+            '{' => depth += 1,
+            '}' if depth == 0 => return false,
+            '}' => depth -= 1,
+            _ => {}
+        }
+    }
+
+    depth == 0
+}
+
+/// Closest known name within a couple of edits to catch any typos.
+fn closest<'a>(name: &str, known: &[&'a str]) -> Option<&'a str> {
+    known
+        .iter()
+        .map(|candidate| (candidate, distance(name, candidate)))
+        .filter(|(_, distance)| *distance <= 2)
+        .min_by_key(|(_, distance)| *distance)
+        .map(|(candidate, _)| *candidate)
+}
+
+/// Levenshtein edit distance between two strings.
+fn distance(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<_>>();
+    let mut previous = (0..=b.len()).collect::<Vec<_>>();
+
+    for (i, a) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+
+        for (j, b) in b.iter().enumerate() {
+            let cost = usize::from(a != *b);
+            current.push(
+                (previous[j] + cost)
+                    .min(previous[j + 1] + 1)
+                    .min(current[j] + 1),
+            );
+        }
+
+        previous = current;
+    }
+
+    previous[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+
+    fn config(contents: &str) -> String {
+        format!("version: 2\n{contents}")
+    }
+
+    #[test]
+    fn valid_default_configs_have_no_problems() {
+        for contents in [
+            include_str!("../../../templates/default.yml"),
+            include_str!("../../../templates/conventional.yml"),
+        ] {
+            assert_eq!(handler(contents, ConfigFormat::Yaml), vec![]);
+        }
+    }
+
+    #[test]
+    fn invalid_yaml_is_reported() {
+        let problems = handler("version: [1", ConfigFormat::Yaml);
+
+        assert_eq!(problems.len(), 1);
+        assert_eq!(problems[0].location, "config");
+    }
+
+    #[test]
+    fn duplicate_keys_are_reported() {
+        let problems = handler(&config(
+            "commit:\n  templates:\n    fix:\n      description: a\n      content: a\n    fix:\n      description: b\n      content: b\n",
+        ), ConfigFormat::Yaml);
+
+        assert_eq!(problems.len(), 1);
+        assert!(problems[0].message.contains("duplicate entry"));
+    }
+
+    #[test]
+    fn unknown_keys_are_reported() {
+        let problems = handler(&config(
+            "commit:\n  name: old\n  templates:\n    fix:\n      descripton: Fix\n      description: Fix\n      content: \"{message}\"\n",
+        ), ConfigFormat::Yaml);
+
+        assert_eq!(
+            problems,
+            vec![
+                Problem::new("commit.name", "Unknown key"),
+                Problem::new("commit.templates.fix.descripton", "Unknown key"),
+            ]
+        );
+    }
+
+    #[test]
+    fn unknown_keys_are_ignored_in_version_1() {
+        let problems = handler(
+            "version: 1\ncommit:\n  name: old\n  templates: {}\n",
+            ConfigFormat::Yaml,
+        );
+
+        assert_eq!(problems, vec![]);
+    }
+
+    #[test]
+    fn unsupported_version_is_reported() {
+        let problems = handler(
+            "version: 99\ncommit:\n  templates: {}\n",
+            ConfigFormat::Yaml,
+        );
+
+        assert_eq!(
+            problems,
+            vec![Problem::new(
+                "version",
+                "Unsupported version 99 expected one of [1, 2]"
+            )]
+        );
+    }
+
+    #[test]
+    fn template_content_problems_are_reported() {
+        let problems = handler(
+            &config(
+                r#"commit:
+  templates:
+    empty:
+      description: empty
+      content: "  "
+    unbalanced:
+      description: unbalanced
+      content: "[{ticket_num] {message}"
+    unclosed:
+      description: unclosed
+      content: "{#if link}{link}"
+"#,
+            ),
+            ConfigFormat::Yaml,
+        );
+
+        assert_eq!(
+            problems,
+            vec![
+                Problem::new("commit.templates.empty", "Template content is empty"),
+                Problem::new("commit.templates.unbalanced", "Unbalanced braces found"),
+                Problem::new(
+                    "commit.templates.unclosed",
+                    "Section \"{#if link}\" is missing a closing \"{/if}\""
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn escaped_braces_are_not_reported_as_unbalanced() {
+        let problems = handler(
+            &config(
+                r#"commit:
+  templates:
+    json:
+      description: json
+      content: '{message} \{"ticket": "{ticket_num}"\} \{'
+"#,
+            ),
+            ConfigFormat::Yaml,
+        );
+
+        assert_eq!(problems, vec![]);
+    }
+
+    #[test]
+    fn shared_footer_and_partials_are_linted_with_each_template() {
+        let problems = handler(
+            &config(
+                r#"commit:
+  footer: "{> issue}"
+  partials:
+    issue: "Issue: {tiket_num}"
+  templates:
+    fix:
+      description: fix
+      content: "{message}"
+    missing:
+      description: missing
+      content: "{> unknown}"
+      footer: false
+"#,
+            ),
+            ConfigFormat::Yaml,
+        );
+
+        assert_eq!(
+            problems,
+            vec![
+                Problem::new(
+                    "commit.templates.fix",
+                    "Unknown placeholder \"tiket_num\" did you mean \"ticket_num\""
+                ),
+                Problem::new(
+                    "commit.templates.missing",
+                    "Unknown partial \"unknown\" not found within the config partials"
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn misspelled_placeholders_are_reported_with_a_suggestion() {
+        let problems = handler(
+            &config(
+                r#"variables: [component]
+commit:
+  templates:
+    fix:
+      description: fix
+      content: "{scpe} {componet} {message} {unknown}"
+"#,
+            ),
+            ConfigFormat::Yaml,
+        );
+
+        assert_eq!(
+            problems,
+            vec![
+                Problem::new(
+                    "commit.templates.fix",
+                    "Unknown placeholder \"scpe\" did you mean \"scope\""
+                ),
+                Problem::new(
+                    "commit.templates.fix",
+                    "Unknown placeholder \"componet\" did you mean \"component\""
+                ),
+                Problem::new("commit.templates.fix", "Unknown placeholder \"unknown\""),
+            ]
+        );
+    }
+
+    #[test]
+    fn branch_templates_yielding_an_invalid_git_ref_once_sanitized_are_reported() {
+        let problems = handler(
+            &config("branch:\n  content: \"...\"\ncommit:\n  templates: {}\n"),
+            ConfigFormat::Yaml,
+        );
+
+        assert_eq!(
+            problems,
+            vec![Problem::new(
+                "branch",
+                "Invalid git ref, Branch name \"\" cannot be empty"
+            )]
+        );
+    }
+
+    #[test]
+    fn branch_templates_sanitized_into_a_valid_git_ref_are_not_reported() {
+        let problems = handler(
+            &config(
+                r#"branch:
+  content: "{type}/{ticket_num} {title}:{scope}"
+commit:
+  templates: {}
+"#,
+            ),
+            ConfigFormat::Yaml,
+        );
+
+        assert_eq!(problems, vec![]);
+    }
+
+    #[test]
+    fn invalid_branch_separators_are_reported() {
+        let problems = handler(
+            &config("branch:\n  content: \"{branch_name}\"\n  separator: \"~\"\ncommit:\n  templates: {}\n"),
+            ConfigFormat::Yaml,
+        );
+
+        assert_eq!(
+            problems,
+            vec![Problem::new(
+                "branch.separator",
+                "Branch separator \"~\" is not valid within a git ref"
+            )]
+        );
+    }
+
+    #[test]
+    fn invalid_prompt_patterns_are_reported() {
+        let problems = handler(
+            &config(
+                r#"commit:
+  templates:
+    fix:
+      description: fix
+      content: "{scope}"
+      prompts:
+        - name: scope
+          validate: "[a-z"
+"#,
+            ),
+            ConfigFormat::Yaml,
+        );
+
+        assert_eq!(problems.len(), 1);
+        assert_eq!(problems[0].location, "commit.templates.fix.prompts");
+    }
+
+    #[test]
+    fn toml_configs_are_linted() {
+        let problems = handler(
+            r#"
+version = 2
+
+[commit.templates.fix]
+description = "Fix"
+content = "fix: {mesage}"
+"#,
+            ConfigFormat::Toml,
+        );
+
+        assert_eq!(problems.len(), 1);
+        assert_eq!(problems[0].location, "commit.templates.fix");
+        assert!(problems[0].message.contains("message"));
+    }
+
+    #[test]
+    fn invalid_toml_is_reported() {
+        let problems = handler("version = [1", ConfigFormat::Toml);
+
+        assert_eq!(problems.len(), 1);
+        assert!(
+            problems[0].message.contains("toml"),
+            "{}",
+            problems[0].message
+        );
+    }
+
+    #[test]
+    fn extending_configs_are_linted_once_merged() {
+        let layer = |key: &str, contents: &str| ConfigLayer {
+            key: key.into(),
+            path: PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+                .join("Cargo.toml")
+                .try_into()
+                .unwrap(),
+            format: ConfigFormat::Yaml,
+            contents: contents.into(),
+        };
+        let base = layer(
+            "team",
+            "version: 2\ncommit:\n  templates:\n    fix:\n      description: Fix\n      content: \"{mesage}\"\n",
+        );
+        let local = layer(
+            "local",
+            "version: 2\nextends: team\ncommit:\n  templates:\n    fix:\n      description: Bug\n",
+        );
+
+        assert!(!handler(&local.contents, local.format).is_empty());
+
+        let problems = layers(&[base, local]);
+
+        assert_eq!(problems.len(), 1);
+        assert_eq!(problems[0].location, "commit.templates.fix");
+    }
+
+    #[test]
+    fn built_in_variables_declared_as_config_variables_are_reported() {
+        let problems = handler(
+            &config("variables: [component, branch_name]\ncommit:\n  templates: {}\n"),
+            ConfigFormat::Yaml,
+        );
+
+        assert_eq!(
+            problems,
+            vec![Problem::new(
+                "variables",
+                "Variable \"branch_name\" is provided by inkan and cannot be declared"
+            )]
+        );
+    }
+
+    #[test]
+    fn alias_collisions_are_reported() {
+        let problems = handler(
+            &config(
+                r#"
+commit:
+  templates:
+    bug:
+      description: Bug
+      content: "{message}"
+      aliases: [fix, bugfix]
+    fix:
+      description: Fix
+      content: "{message}"
+      aliases: [bugfix]
+"#,
+            ),
+            ConfigFormat::Yaml,
+        );
+
+        assert_eq!(
+            problems,
+            vec![
+                Problem::new(
+                    "commit.templates",
+                    "Name or alias \"bugfix\" is claimed by more than one template [bug, fix]"
+                ),
+                Problem::new(
+                    "commit.templates",
+                    "Name or alias \"fix\" is claimed by more than one template [bug, fix]"
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn branch_defaults_with_invalid_patterns_or_unknown_templates_are_reported() {
+        let problems = handler(
+            &config(
+                r#"
+commit:
+  defaults:
+    - branch: ^hotfix/
+      template: fix
+    - branch: "^docs/("
+      template: docs
+  templates:
+    bug:
+      description: Bug
+      content: "{message}"
+      aliases: [fix]
+"#,
+            ),
+            ConfigFormat::Yaml,
+        );
+
+        assert_eq!(problems.len(), 2);
+        assert_eq!(problems[0].location, "commit.defaults[1]");
+        assert!(problems[0].message.contains("invalid branch pattern"));
+        assert_eq!(
+            problems[1],
+            Problem::new("commit.defaults[1]", "Unknown template \"docs\"")
+        );
+    }
+
+    #[test]
+    fn edit_distance_counts_insertions_deletions_and_substitutions() {
+        assert_eq!(distance("scope", "scope"), 0);
+        assert_eq!(distance("scpe", "scope"), 1);
+        assert_eq!(distance("tickt_nun", "ticket_num"), 2);
+        assert_eq!(distance("", "abc"), 3);
+    }
+}
@@ -0,0 +1,423 @@
+use regex::Regex;
+
+use crate::{
+    domain::errors::ConfigError,
+    template_config::{ConfigFormat, TemplateConfig, LATEST_VERSION},
+};
+
+/// Config contents rewritten into the latest version layout.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Migration {
+    pub from: u32,
+    pub to: u32,
+    pub contents: String,
+}
+
+/// Rewrite the raw config contents one version at a time into the latest layout.
+///
+/// Yaml & toml contents are edited as text rather than re-serialized so any comments & formatting are kept.
+pub fn handler(contents: &str, format: ConfigFormat) -> Result<Migration, ConfigError> {
+    let from = TemplateConfig::parse(contents, format)?.version;
+
+    let migrated = match format {
+        ConfigFormat::Json => migrate_json(contents, from)?,
+        ConfigFormat::Yaml | ConfigFormat::Toml => migrate_text(contents, from, format),
+    };
+
+    // Any keys that were previously ignored are now rejected, these are left for the user to fix.
+    let version = TemplateConfig::parse(&migrated, format)?.version;
+
+    // Text edits can miss an unusual layout so the result is checked rather than trusted.
+    if version != LATEST_VERSION {
+        return Err(ConfigError::Invalid {
+            message: format!(
+                "version {version} could not be migrated to {LATEST_VERSION}, update the version manually"
+            ),
+        });
+    }
+
+    Ok(Migration {
+        from,
+        to: LATEST_VERSION,
+        contents: migrated,
+    })
+}
+
+fn migrate_text(contents: &str, from: u32, format: ConfigFormat) -> String {
+    let mut migrated = contents.to_string();
+
+    for version in from..LATEST_VERSION {
+        log::info!(
+            "migrating config from version {} to {}",
+            version,
+            version + 1
+        );
+
+        migrated = match version {
+            1 => v1_to_v2(&migrated, format),
+            _ => migrated,
+        };
+
+        migrated = set_version(&migrated, version + 1, format);
+    }
+
+    migrated
+}
+
+/// Json has no comments to keep so the contents are re-serialized keeping the key order.
+fn migrate_json(contents: &str, from: u32) -> Result<String, ConfigError> {
+    let mut config = ConfigFormat::Json.deserialize::<serde_json::Value>(contents)?;
+
+    for version in from..LATEST_VERSION {
+        log::info!(
+            "migrating config from version {} to {}",
+            version,
+            version + 1
+        );
+
+        if version == 1 {
+            if let Some(commit) = config["commit"].as_object_mut() {
+                commit.remove("name");
+            }
+        }
+
+        config["version"] = (version + 1).into();
+    }
+
+    let mut migrated = serde_json::to_string_pretty(&config).map_err(|e| ConfigError::Invalid {
+        message: e.to_string(),
+    })?;
+    migrated.push('\n');
+
+    Ok(migrated)
+}
+
+/// Rewrites the least indented `version` key which may be quoted, otherwise inserts it when missing.
+fn set_version(contents: &str, version: u32, format: ConfigFormat) -> String {
+    let pattern =
+        Regex::new(r#"(?m)^([ \t]*)(["']?version["']?[ \t]*[:=][ \t]*)(["']?)\d+(["']?)"#)
+            .expect("Valid version regex");
+
+    let top_level = pattern
+        .captures_iter(contents)
+        .min_by_key(|captures| captures[1].len());
+
+    let Some(captures) = top_level else {
+        // Left as is when declared in a layout that can't be rewritten i.e yaml flow style.
+        let declared = Regex::new(r#"["']?version["']?[ \t]*[:=]"#).expect("Valid version regex");
+
+        return match declared.is_match(contents) {
+            true => contents.to_string(),
+            false => insert_version(contents, version, format),
+        };
+    };
+
+    let matched = captures.get(0).expect("Whole match");
+
+    format!(
+        "{}{}{}{}{version}{}{}",
+        &contents[..matched.start()],
+        &captures[1],
+        &captures[2],
+        &captures[3],
+        &captures[4],
+        &contents[matched.end()..]
+    )
+}
+
+fn insert_version(contents: &str, version: u32, format: ConfigFormat) -> String {
+    log::info!("inserting missing 'version' key");
+
+    let line = match format {
+        ConfigFormat::Toml => format!("version = {version}\n"),
+        _ => format!("version: {version}\n"),
+    };
+
+    // A yaml document start marker has to stay first.
+    match contents.strip_prefix("---\n") {
+        Some(rest) => format!("---\n{line}{rest}"),
+        None => format!("{line}{contents}"),
+    }
+}
+
+/// Removes the unused `commit.name` key which is no longer ignored.
+fn v1_to_v2(contents: &str, format: ConfigFormat) -> String {
+    match format {
+        ConfigFormat::Toml => remove_toml_key(contents, "commit", "name"),
+        _ => remove_yaml_key(contents, "commit", "name"),
+    }
+}
+
+/// Removes the key along with any more indented lines continuing its value i.e a block or multi-line string.
+fn remove_yaml_key(contents: &str, parent: &str, key: &str) -> String {
+    let mut migrated = String::new();
+    let mut within_parent = false;
+    let mut child_indent = None;
+    // Indent of the removed key while its value is still being skipped.
+    let mut removing: Option<usize> = None;
+    // Blank lines are only dropped once known to be within the removed value.
+    let mut blank_lines = String::new();
+
+    for line in contents.split_inclusive('\n') {
+        let trimmed = line.trim_start();
+        let indent = line.len() - trimmed.len();
+        let is_content = !trimmed.trim().is_empty() && !trimmed.starts_with('#');
+
+        if let Some(removed_indent) = removing {
+            if trimmed.trim().is_empty() {
+                blank_lines.push_str(line);
+                continue;
+            }
+
+            if indent > removed_indent {
+                blank_lines.clear();
+                continue;
+            }
+
+            removing = None;
+        }
+
+        migrated.push_str(&blank_lines);
+        blank_lines.clear();
+
+        if is_content && indent == 0 {
+            within_parent = is_key(trimmed, parent, ':');
+            child_indent = None;
+        } else if within_parent && is_content {
+            let child_indent = *child_indent.get_or_insert(indent);
+
+            if indent == child_indent && is_key(trimmed, key, ':') {
+                log::info!("removing unused '{}.{}' key", parent, key);
+                removing = Some(indent);
+                continue;
+            }
+        }
+
+        migrated.push_str(line);
+    }
+
+    migrated.push_str(&blank_lines);
+
+    migrated
+}
+
+fn remove_toml_key(contents: &str, table: &str, key: &str) -> String {
+    let mut migrated = String::new();
+    let mut within_table = false;
+
+    for line in contents.split_inclusive('\n') {
+        let trimmed = line.trim();
+
+        if trimmed.starts_with('[') {
+            within_table = trimmed == format!("[{table}]");
+        } else if within_table && is_key(trimmed, key, '=') {
+            log::info!("removing unused '{}.{}' key", table, key);
+            continue;
+        }
+
+        migrated.push_str(line);
+    }
+
+    migrated
+}
+
+fn is_key(line: &str, key: &str, separator: char) -> bool {
+    line.strip_prefix(key)
+        .map(|rest| rest.trim_start().starts_with(separator))
+        .unwrap_or(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn v1_config_is_migrated_keeping_comments() {
+        let contents = r#"# Team config
+version: 1 # schema version
+
+commit:
+  # Not used
+  name: team
+  templates:
+    fix:
+      # Used for bugs
+      description: Fix
+      content: "{message}"
+      prompts:
+        - name: scope
+"#;
+
+        let migration = handler(contents, ConfigFormat::Yaml).unwrap();
+
+        assert_eq!(
+            migration,
+            Migration {
+                from: 1,
+                to: 2,
+                contents: r#"# Team config
+version: 2 # schema version
+
+commit:
+  # Not used
+  templates:
+    fix:
+      # Used for bugs
+      description: Fix
+      content: "{message}"
+      prompts:
+        - name: scope
+"#
+                .into()
+            }
+        );
+    }
+
+    #[test]
+    fn v1_config_block_name_is_removed_along_with_its_value() {
+        let contents = "version: 1\ncommit:\n  name: |\n    team\n\n    name\n\n  templates: {}\n";
+
+        let migration = handler(contents, ConfigFormat::Yaml).unwrap();
+
+        assert_eq!(
+            migration.contents,
+            "version: 2\ncommit:\n\n  templates: {}\n"
+        );
+    }
+
+    #[test]
+    fn quoted_and_indented_versions_are_migrated() {
+        let contents = "\"version\": 1\ncommit:\n  templates: {}\n";
+        let migration = handler(contents, ConfigFormat::Yaml).unwrap();
+        assert_eq!(
+            migration.contents,
+            "\"version\": 2\ncommit:\n  templates: {}\n"
+        );
+
+        let contents = "  version = 1\n\n[commit.templates]\n";
+        let migration = handler(contents, ConfigFormat::Toml).unwrap();
+        assert_eq!(migration.contents, "  version = 2\n\n[commit.templates]\n");
+    }
+
+    #[test]
+    fn missing_version_is_inserted() {
+        assert_eq!(
+            set_version("---\ncommit: {}\n", 2, ConfigFormat::Yaml),
+            "---\nversion: 2\ncommit: {}\n"
+        );
+        assert_eq!(
+            set_version("[commit]\n", 2, ConfigFormat::Toml),
+            "version = 2\n[commit]\n"
+        );
+    }
+
+    #[test]
+    fn version_that_cannot_be_rewritten_errors() {
+        let contents = "{version: 1, commit: {templates: {}}}\n";
+
+        let error = handler(contents, ConfigFormat::Yaml).unwrap_err();
+
+        assert_eq!(
+            error,
+            ConfigError::Invalid {
+                message: "version 1 could not be migrated to 2, update the version manually".into()
+            }
+        );
+    }
+
+    #[test]
+    fn latest_config_is_left_as_is() {
+        let contents = "version: 2\ncommit:\n  templates: {}\n";
+
+        let migration = handler(contents, ConfigFormat::Yaml).unwrap();
+
+        assert_eq!(
+            migration,
+            Migration {
+                from: 2,
+                to: 2,
+                contents: contents.into()
+            }
+        );
+    }
+
+    #[test]
+    fn unknown_keys_left_after_migrating_error() {
+        let contents = "version: 1\ncommit:\n  templates:\n    fix:\n      descripton: Fix\n      description: Fix\n      content: a\n";
+
+        let error = handler(contents, ConfigFormat::Yaml).unwrap_err();
+
+        assert_eq!(
+            error,
+            ConfigError::UnknownKeys {
+                keys: vec!["commit.templates.fix.descripton".into()]
+            }
+        );
+    }
+
+    #[test]
+    fn unsupported_version_errors() {
+        let error =
+            handler("version: 3\ncommit:\n  templates: {}\n", ConfigFormat::Yaml).unwrap_err();
+
+        assert_eq!(
+            error,
+            ConfigError::UnsupportedVersion {
+                version: 3,
+                supported: vec![1, 2]
+            }
+        );
+    }
+
+    #[test]
+    fn v1_toml_config_is_migrated_keeping_comments() {
+        let contents = r#"# Team config
+version = 1
+
+[commit]
+name = "team" # Not used
+
+[commit.templates.fix]
+description = "Fix"
+content = "{message}"
+"#;
+
+        let migration = handler(contents, ConfigFormat::Toml).unwrap();
+
+        assert_eq!(
+            migration.contents,
+            r#"# Team config
+version = 2
+
+[commit]
+
+[commit.templates.fix]
+description = "Fix"
+content = "{message}"
+"#
+        );
+    }
+
+    #[test]
+    fn v1_json_config_is_migrated_keeping_key_order() {
+        let contents = r#"{"version": 1, "commit": {"name": "team", "templates": {"fix": {"description": "Fix", "content": "{message}"}}}}"#;
+
+        let migration = handler(contents, ConfigFormat::Json).unwrap();
+
+        assert_eq!(
+            migration.contents,
+            r#"{
+  "version": 2,
+  "commit": {
+    "templates": {
+      "fix": {
+        "description": "Fix",
+        "content": "{message}"
+      }
+    }
+  }
+}
+"#
+        );
+    }
+}
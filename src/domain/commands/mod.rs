@@ -1,3 +1,7 @@
+pub mod branches;
 pub mod checkout;
 pub mod commit;
 pub mod context;
+pub mod gc;
+pub mod lint;
+pub mod migrate;
@@ -17,6 +17,9 @@ pub enum Errors {
     #[error(transparent)]
     PersistError(PersistError),
 
+    #[error(transparent)]
+    Template(TemplateError),
+
     #[error("Validation error occurred {}", .message.to_lowercase())]
     ValidationError {
         message: String,
@@ -29,8 +32,8 @@ pub enum UserInputError {
     #[error("Missing required {name:?} input")]
     Required { name: String },
 
-    #[error("Invalid command {name:?} found")]
-    InvalidCommand { name: String },
+    #[error("Invalid command {name:?} found expected one of [{}]", .valid.join(", "))]
+    InvalidCommand { name: String, valid: Vec<String> },
 
     #[error("Input prompt cancelled by user")]
     Cancelled,
@@ -47,9 +50,24 @@ pub enum GitError {
     #[error("Failed retrieve the current git root directory")]
     RootDirectory,
 
+    #[error("Failed retrieve the git config value {key:?}")]
+    ConfigValue { key: String },
+
+    #[error("Failed retrieve the commit hash for {revision:?}")]
+    ShortSha { revision: String },
+
+    #[error("Failed retrieve the working tree status")]
+    Status,
+
+    #[error("Failed retrieve the local git branches")]
+    Branches,
+
     #[error("Failed to checkout branch {name:?}")]
     Checkout { name: String },
 
+    #[error("Failed to create branch {name:?} from {from:?} expected a valid git ref")]
+    CheckoutFrom { name: String, from: String },
+
     #[error("Failed to apply commit")]
     Commit,
 
@@ -77,3 +95,47 @@ pub enum PersistError {
     #[error("Unknown error occurred while connecting persisted store")]
     Unknown(anyhow::Error),
 }
+
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum TemplateError {
+    #[error("Section {section:?} is missing a closing \"{{/if}}\"")]
+    Unclosed { section: String },
+
+    #[error("Found {token:?} without an opening \"{{#if}}\" section")]
+    Unexpected { token: String },
+
+    #[error("Unknown filter {name:?} expected one of upper, lower, slug, truncate or wrap")]
+    UnknownFilter { name: String },
+
+    #[error("Invalid filter {name:?} {}", .message.to_lowercase())]
+    InvalidFilter { name: String, message: String },
+
+    #[error("Unknown partial {name:?} not found within the config partials")]
+    UnknownPartial { name: String },
+
+    #[error("Partial {name:?} cannot include itself")]
+    RecursivePartial { name: String },
+}
+
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum ConfigError {
+    #[error("Invalid config {message}")]
+    Invalid { message: String },
+
+    #[error("Unsupported config version {version} expected one of {supported:?}")]
+    UnsupportedVersion { version: u64, supported: Vec<u32> },
+
+    #[error("Unknown config keys {keys:?} found, unknown keys are not allowed from version 2")]
+    UnknownKeys { keys: Vec<String> },
+
+    #[error("Config '{key}' is extended more than once forming a cycle")]
+    CircularExtends { key: String },
+
+    #[error("Template names & aliases {names:?} are claimed by more than one template")]
+    AliasCollision { names: Vec<String> },
+
+    #[error(
+        "Variables {names:?} are provided by inkan and cannot be declared as config variables"
+    )]
+    ReservedVariables { names: Vec<String> },
+}
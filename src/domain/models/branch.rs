@@ -1,3 +1,5 @@
+use std::collections::BTreeMap;
+
 use chrono::{DateTime, Utc};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -5,9 +7,12 @@ pub struct Branch {
     pub name: String,
     pub ticket: String,
     pub created: DateTime<Utc>,
-    pub data: Option<Vec<u8>>,
+    /// User defined context variables declared within the config i.e `component`.
+    pub variables: BTreeMap<String, String>,
     pub link: Option<String>,
     pub scope: Option<String>,
+    /// Repository the branch belongs to, only missing for branches stored before it was recorded.
+    pub repo: Option<String>,
 }
 
 impl Branch {
@@ -24,9 +29,19 @@ impl Branch {
             ticket: ticket.unwrap_or_else(|| name.into()),
             link,
             scope,
-            data: None,
+            variables: BTreeMap::new(),
+            repo: Some(repo.trim().into()),
         }
     }
+
+    /// Git branch name without the repository prefix i.e `feature/login`.
+    pub fn branch_name(&self) -> &str {
+        self.repo
+            .as_ref()
+            .and_then(|repo| self.name.strip_prefix(repo.as_str()))
+            .and_then(|name| name.strip_prefix('-'))
+            .unwrap_or(&self.name)
+    }
 }
 
 #[cfg(test)]
@@ -60,12 +75,28 @@ mod tests {
         assert_eq!(branch.ticket, ticket);
         assert_eq!(branch.scope.unwrap(), scope);
         assert_eq!(branch.link.unwrap(), link);
+        assert_eq!(branch.repo, Some(repo));
         assert!(branch.created > now);
-        assert_eq!(branch.data, None);
+        assert!(branch.variables.is_empty());
 
         Ok(())
     }
 
+    #[test]
+    fn branch_name_removes_the_repo_prefix() {
+        let branch = Branch::new("feature/login", "inkan", None, None, None);
+        assert_eq!(branch.branch_name(), "feature/login");
+
+        let branch = Branch::new("main", "inkan-cli", None, None, None);
+        assert_eq!(branch.branch_name(), "main");
+
+        let branch = Branch {
+            repo: None,
+            ..branch
+        };
+        assert_eq!(branch.branch_name(), "inkan-cli-main");
+    }
+
     #[test]
     fn creating_branch_without_ticket_defaults_to_name() -> anyhow::Result<()> {
         // Arrange
@@ -80,7 +111,7 @@ mod tests {
         assert_eq!(branch.name, format!("{}-{}", &repo, &name));
         assert_eq!(branch.ticket, name);
         assert!(branch.created > now);
-        assert_eq!(branch.data, None);
+        assert!(branch.variables.is_empty());
 
         Ok(())
     }
@@ -100,7 +131,7 @@ mod tests {
         assert_eq!(branch.name, format!("{}-{}", &repo.trim(), &name.trim()));
         assert_eq!(branch.ticket, ticket);
         assert!(branch.created > now);
-        assert_eq!(branch.data, None);
+        assert!(branch.variables.is_empty());
 
         Ok(())
     }
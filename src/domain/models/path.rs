@@ -1,4 +1,7 @@
-use std::path::{Path, PathBuf};
+use std::{
+    fmt,
+    path::{Path, PathBuf},
+};
 use thiserror::Error;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -68,9 +71,15 @@ impl From<AbsolutePath> for PathBuf {
     }
 }
 
-impl ToString for AbsolutePath {
-    fn to_string(&self) -> String {
-        self.path.to_str().unwrap_or_default().into()
+impl AsRef<Path> for AbsolutePath {
+    fn as_ref(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl fmt::Display for AbsolutePath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.path.to_str().unwrap_or_default())
     }
 }
 
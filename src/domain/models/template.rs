@@ -24,8 +24,7 @@ impl fmt::Display for Template {
         write!(
             f,
             "Configuration is set to '{}' at path:\n {}",
-            key,
-            self.path.to_string()
+            key, self.path
         )
     }
 }
@@ -0,0 +1,196 @@
+use std::fmt;
+
+use crate::domain::errors::TemplateError;
+
+/// Transformation applied to a placeholder value i.e `{scope|lower}`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Filter {
+    /// Converts the value to uppercase.
+    Upper,
+    /// Converts the value to lowercase.
+    Lower,
+    /// Converts the value to a lowercase dash separated slug i.e `Add login` -> `add-login`.
+    Slug,
+    /// Limits the value to a maximum number of characters.
+    Truncate(usize),
+    /// Wraps the value onto new lines once a line exceeds the given width.
+    Wrap(usize),
+}
+
+impl Filter {
+    pub fn parse(expression: &str) -> Result<Filter, TemplateError> {
+        let (name, argument) = match expression.split_once(':') {
+            Some((name, argument)) => (name.trim(), Some(argument.trim())),
+            None => (expression.trim(), None),
+        };
+
+        let filter = match (name, argument) {
+            ("upper", None) => Filter::Upper,
+            ("lower", None) => Filter::Lower,
+            ("slug", None) => Filter::Slug,
+            ("truncate", argument) => Filter::Truncate(Self::parse_width(name, argument)?),
+            ("wrap", argument) => Filter::Wrap(Self::parse_width(name, argument)?),
+            ("upper" | "lower" | "slug", Some(_)) => {
+                return Err(TemplateError::InvalidFilter {
+                    name: name.into(),
+                    message: "Does not accept an argument".into(),
+                })
+            }
+            (name, _) => return Err(TemplateError::UnknownFilter { name: name.into() }),
+        };
+
+        Ok(filter)
+    }
+
+    fn parse_width(name: &str, argument: Option<&str>) -> Result<usize, TemplateError> {
+        argument
+            .and_then(|argument| argument.parse::<usize>().ok())
+            .filter(|width| *width > 0)
+            .ok_or_else(|| TemplateError::InvalidFilter {
+                name: name.into(),
+                message: format!("Expected a positive number i.e '{name}:72'"),
+            })
+    }
+
+    pub fn apply(&self, value: &str) -> String {
+        match self {
+            Filter::Upper => value.to_uppercase(),
+            Filter::Lower => value.to_lowercase(),
+            Filter::Slug => slug(value),
+            Filter::Truncate(width) => value.chars().take(*width).collect::<String>(),
+            Filter::Wrap(width) => wrap(value, *width),
+        }
+    }
+}
+
+impl fmt::Display for Filter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Filter::Upper => write!(f, "upper"),
+            Filter::Lower => write!(f, "lower"),
+            Filter::Slug => write!(f, "slug"),
+            Filter::Truncate(width) => write!(f, "truncate:{width}"),
+            Filter::Wrap(width) => write!(f, "wrap:{width}"),
+        }
+    }
+}
+
+fn slug(value: &str) -> String {
+    let slug = value
+        .to_lowercase()
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { '-' })
+        .collect::<String>();
+
+    slug.split('-')
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+fn wrap(value: &str, width: usize) -> String {
+    value
+        .lines()
+        .map(|line| {
+            let mut lines: Vec<String> = vec![];
+            let mut current = String::new();
+
+            for word in line.split_whitespace() {
+                if !current.is_empty() && current.chars().count() + 1 + word.chars().count() > width
+                {
+                    lines.push(std::mem::take(&mut current));
+                }
+
+                if !current.is_empty() {
+                    current.push(' ');
+                }
+
+                current.push_str(word);
+            }
+
+            lines.push(current);
+            lines.join("\n")
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn filters_are_parsed_by_name() {
+        let filters = [
+            ("upper", Filter::Upper),
+            ("lower", Filter::Lower),
+            ("slug", Filter::Slug),
+            ("truncate:72", Filter::Truncate(72)),
+            ("wrap: 50", Filter::Wrap(50)),
+        ];
+
+        for (expression, expected) in filters {
+            assert_eq!(Filter::parse(expression).unwrap(), expected);
+            assert_eq!(
+                Filter::parse(&expected.to_string()).unwrap(),
+                expected,
+                "display should round trip"
+            );
+        }
+    }
+
+    #[test]
+    fn unknown_filter_errors() {
+        let error = Filter::parse("uppercase").unwrap_err();
+
+        assert_eq!(
+            error,
+            TemplateError::UnknownFilter {
+                name: "uppercase".into()
+            }
+        );
+    }
+
+    #[test]
+    fn invalid_filter_arguments_error() {
+        for expression in ["truncate", "truncate:", "truncate:abc", "wrap:0", "upper:1"] {
+            let error = Filter::parse(expression).unwrap_err();
+
+            assert!(
+                matches!(error, TemplateError::InvalidFilter { .. }),
+                "{expression}"
+            );
+        }
+    }
+
+    #[test]
+    fn casing_filters_are_applied() {
+        assert_eq!(Filter::Upper.apply("abc-12"), "ABC-12");
+        assert_eq!(Filter::Lower.apply("Core"), "core");
+    }
+
+    #[test]
+    fn slug_filter_replaces_non_alphanumeric_characters_with_a_single_dash() {
+        assert_eq!(Filter::Slug.apply("Add login page!"), "add-login-page");
+        assert_eq!(
+            Filter::Slug.apply("  Fix:  the  /parser/ "),
+            "fix-the-parser"
+        );
+    }
+
+    #[test]
+    fn truncate_filter_limits_characters() {
+        assert_eq!(Filter::Truncate(3).apply("abcdef"), "abc");
+        assert_eq!(Filter::Truncate(10).apply("abc"), "abc");
+        assert_eq!(Filter::Truncate(2).apply("🐛🐛🐛"), "🐛🐛");
+    }
+
+    #[test]
+    fn wrap_filter_breaks_lines_on_whitespace() {
+        assert_eq!(
+            Filter::Wrap(10).apply("the quick brown fox jumps"),
+            "the quick\nbrown fox\njumps"
+        );
+        assert_eq!(Filter::Wrap(10).apply("first\nsecond"), "first\nsecond");
+    }
+}
@@ -0,0 +1,309 @@
+use std::fmt;
+
+/// Prefix of placeholder names resolved from environment variables i.e `{env.USER}`.
+pub const ENV_PREFIX: &str = "env.";
+
+/// Lexical token produced while scanning template content.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Token {
+    /// Literal text copied into the output as is.
+    Text(String),
+    /// Placeholder expression found between a pair of braces i.e `{ticket_num|upper}`.
+    Placeholder(String),
+    /// Start of a conditional section i.e `{#if link}`.
+    If(String),
+    /// Start of the alternate branch of a conditional section `{#else}`.
+    Else,
+    /// End of a conditional section `{/if}`.
+    EndIf,
+    /// Inclusion of a named partial i.e `{> issue}`.
+    Include(String),
+    /// Brace escaped with a backslash i.e `\{` to be kept as literal text.
+    Escaped(char),
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Token::Text(text) => write!(f, "{text}"),
+            Token::Placeholder(name) => write!(f, "{{{name}}}"),
+            Token::If(name) => write!(f, "{{#if {name}}}"),
+            Token::Else => write!(f, "{{#else}}"),
+            Token::EndIf => write!(f, "{{/if}}"),
+            Token::Include(name) => write!(f, "{{> {name}}}"),
+            Token::Escaped(c) => write!(f, "\\{c}"),
+        }
+    }
+}
+
+/// Split template content into literal text, placeholder and section tokens in a single pass.
+///
+/// Any brace that does not wrap a valid placeholder or section is kept as literal text
+/// so content such as markdown checklists `[ ]` or stray braces are left untouched.
+/// Braces escaped with a backslash i.e `\{message\}` are always kept as literal text.
+pub fn tokenize(content: &str) -> Vec<Token> {
+    let mut tokens = vec![];
+    let mut text = String::new();
+    let mut rest = content;
+
+    while let Some(start) = rest.find(['{', '\\']) {
+        text.push_str(&rest[..start]);
+
+        if let Some(after_escape) = rest[start..].strip_prefix('\\') {
+            match after_escape.chars().next() {
+                Some(c @ ('{' | '}')) => {
+                    push_text(&mut tokens, &mut text);
+                    tokens.push(Token::Escaped(c));
+                    rest = &after_escape[1..];
+                }
+                _ => {
+                    text.push('\\');
+                    rest = after_escape;
+                }
+            }
+
+            continue;
+        }
+
+        let after_open = &rest[start + 1..];
+
+        let tag = after_open.find('}').and_then(|end| {
+            let inner = &after_open[..end];
+            into_token(inner).map(|token| (token, inner.len()))
+        });
+
+        match tag {
+            Some((token, len)) => {
+                push_text(&mut tokens, &mut text);
+                tokens.push(token);
+                rest = &after_open[len + 1..];
+            }
+            None => {
+                text.push('{');
+                rest = after_open;
+            }
+        }
+    }
+
+    text.push_str(rest);
+    push_text(&mut tokens, &mut text);
+
+    tokens
+}
+
+fn push_text(tokens: &mut Vec<Token>, text: &mut String) {
+    if !text.is_empty() {
+        tokens.push(Token::Text(std::mem::take(text)));
+    }
+}
+
+fn into_token(inner: &str) -> Option<Token> {
+    if let Some(name) = inner.strip_prefix("#if ") {
+        let name = name.trim();
+        return is_name(name).then(|| Token::If(name.into()));
+    }
+
+    if let Some(name) = inner.strip_prefix('>') {
+        let name = name.trim();
+        return is_identifier(name).then(|| Token::Include(name.into()));
+    }
+
+    match inner.trim() {
+        "#else" => Some(Token::Else),
+        "/if" => Some(Token::EndIf),
+        _ if is_placeholder(inner) => Some(Token::Placeholder(inner.into())),
+        _ => None,
+    }
+}
+
+/// Placeholder expressions start with a name optionally followed by a required marker,
+/// default value and filters i.e `scope!`, `scope:core` or `scope|lower`.
+fn is_placeholder(expression: &str) -> bool {
+    let declaration = expression.split('|').next().unwrap_or_default();
+    let name = declaration.split(':').next().unwrap_or_default();
+
+    is_name(name.strip_suffix('!').unwrap_or(name))
+}
+
+/// Context value names are identifiers optionally prefixed with `env.` for environment variables.
+fn is_name(name: &str) -> bool {
+    is_identifier(name.strip_prefix(ENV_PREFIX).unwrap_or(name))
+}
+
+fn is_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+
+    match chars.next() {
+        Some(first) if first.is_ascii_alphabetic() || first == '_' => {
+            chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn text_and_placeholders_are_split_into_tokens() {
+        let tokens = tokenize("[{ticket_num}] {message}");
+
+        assert_eq!(
+            tokens,
+            vec![
+                Token::Text("[".into()),
+                Token::Placeholder("ticket_num".into()),
+                Token::Text("] ".into()),
+                Token::Placeholder("message".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn placeholder_filters_are_kept_within_the_token() {
+        let tokens = tokenize("{scope|lower}: {message|truncate:72}");
+
+        assert_eq!(
+            tokens,
+            vec![
+                Token::Placeholder("scope|lower".into()),
+                Token::Text(": ".into()),
+                Token::Placeholder("message|truncate:72".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn placeholder_defaults_and_required_markers_are_kept_within_the_token() {
+        let tokens = tokenize("{scope:core} {ticket_num!}");
+
+        assert_eq!(
+            tokens,
+            vec![
+                Token::Placeholder("scope:core".into()),
+                Token::Text(" ".into()),
+                Token::Placeholder("ticket_num!".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn environment_variable_placeholders_are_split_into_tokens() {
+        let tokens = tokenize("{env.USER}{#if env.CI}ci{/if}{env.}{env.1st}");
+
+        assert_eq!(
+            tokens,
+            vec![
+                Token::Placeholder("env.USER".into()),
+                Token::If("env.CI".into()),
+                Token::Text("ci".into()),
+                Token::EndIf,
+                Token::Text("{env.}{env.1st}".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn braces_without_a_valid_name_are_kept_as_text() {
+        for content in [
+            "{ }",
+            "{}",
+            "{1st}",
+            "{not valid}",
+            "{|upper}",
+            "{ open",
+            "close }",
+        ] {
+            assert_eq!(tokenize(content), vec![Token::Text(content.into())]);
+        }
+    }
+
+    #[test]
+    fn conditional_sections_are_split_into_tokens() {
+        let tokens = tokenize("{#if link}Issue: {link}{#else}No issue{/if}");
+
+        assert_eq!(
+            tokens,
+            vec![
+                Token::If("link".into()),
+                Token::Text("Issue: ".into()),
+                Token::Placeholder("link".into()),
+                Token::Else,
+                Token::Text("No issue".into()),
+                Token::EndIf,
+            ]
+        );
+    }
+
+    #[test]
+    fn invalid_sections_are_kept_as_text() {
+        for content in ["{#if}", "{#if not valid}", "{#when link}", "{/when}"] {
+            assert_eq!(tokenize(content), vec![Token::Text(content.into())]);
+        }
+    }
+
+    #[test]
+    fn partial_includes_are_split_into_tokens() {
+        let tokens = tokenize("{message}{>issue}{> footer }{>}{> not valid}");
+
+        assert_eq!(
+            tokens,
+            vec![
+                Token::Placeholder("message".into()),
+                Token::Include("issue".into()),
+                Token::Include("footer".into()),
+                Token::Text("{>}{> not valid}".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn escaped_braces_are_split_into_tokens() {
+        let tokens = tokenize("\\{message\\} {\"key\": \\{{scope}\\}}");
+
+        assert_eq!(
+            tokens,
+            vec![
+                Token::Escaped('{'),
+                Token::Text("message".into()),
+                Token::Escaped('}'),
+                Token::Text(" {\"key\": ".into()),
+                Token::Escaped('{'),
+                Token::Placeholder("scope".into()),
+                Token::Escaped('}'),
+                Token::Text("}".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn backslashes_not_before_a_brace_are_kept_as_text() {
+        for content in ["C:\\path", "\\n", "\\", "\\\\"] {
+            assert_eq!(tokenize(content), vec![Token::Text(content.into())]);
+        }
+    }
+
+    #[test]
+    fn escaped_tokens_display_as_written() {
+        let content = "\\{message\\}";
+
+        let displayed: String = tokenize(content).iter().map(Token::to_string).collect();
+
+        assert_eq!(displayed, content);
+    }
+
+    #[test]
+    fn double_braces_wrap_a_single_placeholder() {
+        let tokens = tokenize("{{target}}");
+
+        assert_eq!(
+            tokens,
+            vec![
+                Token::Text("{".into()),
+                Token::Placeholder("target".into()),
+                Token::Text("}".into()),
+            ]
+        );
+    }
+}
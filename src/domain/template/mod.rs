@@ -0,0 +1,479 @@
+mod filter;
+mod lexer;
+mod parser;
+mod render;
+
+use std::collections::HashMap;
+
+use super::errors::{TemplateError, UserInputError};
+
+pub use filter::Filter;
+pub use lexer::{Token, ENV_PREFIX};
+pub use parser::{Conditional, Node, Placeholder};
+
+/// Replace any partial includes i.e `{> issue}` with the named partial content.
+pub fn include_partials(
+    content: &str,
+    partials: &HashMap<String, String>,
+) -> Result<String, TemplateError> {
+    include(content, partials, &mut vec![])
+}
+
+fn include<'a>(
+    content: &str,
+    partials: &'a HashMap<String, String>,
+    including: &mut Vec<&'a str>,
+) -> Result<String, TemplateError> {
+    let mut included = String::new();
+
+    for token in lexer::tokenize(content) {
+        let Token::Include(name) = token else {
+            included.push_str(&token.to_string());
+            continue;
+        };
+
+        let (name, partial) = partials
+            .get_key_value(&name)
+            .ok_or(TemplateError::UnknownPartial { name })?;
+
+        if including.contains(&name.as_str()) {
+            return Err(TemplateError::RecursivePartial { name: name.clone() });
+        }
+
+        including.push(name);
+        included.push_str(&include(partial, partials, including)?);
+        including.pop();
+    }
+
+    Ok(included)
+}
+
+fn collect_names<'a>(nodes: &'a [Node], names: &mut Vec<&'a str>) {
+    for node in nodes {
+        match node {
+            Node::Text(_) | Node::Escaped(_) => {}
+            Node::Placeholder(placeholder) => names.push(&placeholder.name),
+            Node::Conditional(conditional) => {
+                names.push(&conditional.name);
+                collect_names(&conditional.then, names);
+                collect_names(&conditional.otherwise, names);
+            }
+        }
+    }
+}
+
+/// Template content parsed once into an abstract syntax tree ready to be rendered.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ast {
+    nodes: Vec<Node>,
+}
+
+impl Ast {
+    pub fn parse(content: &str) -> Result<Ast, TemplateError> {
+        let tokens = lexer::tokenize(content);
+
+        Ok(Ast {
+            nodes: parser::parse(tokens)?,
+        })
+    }
+
+    pub fn nodes(&self) -> &[Node] {
+        &self.nodes
+    }
+
+    /// Names of every placeholder and conditional section in order of appearance.
+    pub fn names(&self) -> Vec<&str> {
+        let mut names = vec![];
+        collect_names(&self.nodes, &mut names);
+
+        names
+    }
+
+    /// Values of any environment variables referenced within the template i.e `{env.USER}`.
+    pub fn environment(&self) -> HashMap<&str, Option<String>> {
+        self.names()
+            .into_iter()
+            .filter_map(|name| {
+                let variable = name.strip_prefix(ENV_PREFIX)?;
+                Some((name, std::env::var(variable).ok()))
+            })
+            .collect()
+    }
+
+    /// Inject context values into the template in a single pass.
+    ///
+    /// Placeholders with an empty value fallback to their default otherwise are removed
+    /// along with any surrounding brackets or joiners, unless marked as required.
+    /// Placeholders missing from the context are left as is.
+    pub fn render(
+        &self,
+        context: &HashMap<&str, Option<String>>,
+    ) -> Result<String, UserInputError> {
+        render::render(&self.nodes, context)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render_empty(content: &str) -> String {
+        Ast::parse(content)
+            .unwrap()
+            .render(&HashMap::from([("target", None)]))
+            .unwrap()
+    }
+
+    #[test]
+    fn brackets_match() {
+        let templates = [
+            "[{target}]",
+            "[{target}] ",
+            "[{target}]\t",
+            "({target})",
+            "({target}) ",
+            "({target})\t",
+            "{{target}}",
+            "{{target}} ",
+            "{{target}}\t",
+            "[{target}]({target}){{target}}",
+        ];
+
+        for template in templates {
+            assert!(render_empty(template).is_empty())
+        }
+    }
+
+    #[test]
+    fn hanging_connectors_are_removed() {
+        let templates = [
+            ("-{target}-", "-"),
+            ("hi-{target}-bye", "hi-bye"),
+            ("_{target}_", "_"),
+            ("hi_{target}_bye", "hi_bye"),
+            ("/{target}/", "/"),
+            ("-{target}", ""),
+            ("{target}-", ""),
+            ("_{target}", ""),
+            ("{target}_", ""),
+            ("hi {target} bye", "hi bye"),
+        ];
+
+        for (template, expected) in templates {
+            assert_eq!(render_empty(template), expected);
+        }
+    }
+
+    #[test]
+    fn adjacent_empty_placeholders_are_removed_regardless_of_order() {
+        let context = HashMap::from([
+            ("scope", None),
+            ("ticket_num", Some("".into())),
+            ("message", Some("msg".into())),
+        ]);
+
+        let templates = [
+            ("({scope}) [{ticket_num}] {message}", "msg"),
+            ("[{ticket_num}] ({scope}) {message}", "msg"),
+            ("[{ticket_num}{scope}] {message}", "msg"),
+            ("{message}-{scope}-{ticket_num}", "msg"),
+            ("{scope}-{ticket_num}-{message}", "msg"),
+        ];
+
+        for (template, expected) in templates {
+            assert_eq!(
+                Ast::parse(template).unwrap().render(&context).unwrap(),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn injected_values_are_never_treated_as_template_syntax() {
+        let context = HashMap::from([("scope", Some("[{message}]".into())), ("message", None)]);
+
+        let actual = Ast::parse("{scope} {message}")
+            .unwrap()
+            .render(&context)
+            .unwrap();
+
+        assert_eq!(actual, "[{message}]");
+    }
+
+    #[test]
+    fn unknown_placeholders_are_left_as_is() {
+        let actual = Ast::parse("[{unknown}] {target}")
+            .unwrap()
+            .render(&HashMap::from([("target", None)]))
+            .unwrap();
+
+        assert_eq!(actual, "[{unknown}]");
+    }
+
+    #[test]
+    fn conditional_section_is_rendered_when_value_exists() {
+        let template = "msg\n\n{#if link}Issue: {ticket_num} {link}{/if}";
+
+        let context = HashMap::from([
+            ("ticket_num", Some("T-1".into())),
+            ("link", Some("http://ticket/T-1".into())),
+        ]);
+        let actual = Ast::parse(template).unwrap().render(&context).unwrap();
+
+        assert_eq!(actual, "msg\n\nIssue: T-1 http://ticket/T-1");
+    }
+
+    #[test]
+    fn conditional_section_is_skipped_when_value_is_missing_or_empty() {
+        let template = "msg\n\n{#if link}Issue: {ticket_num} {link}{/if}";
+
+        for link in [None, Some("".into()), Some("  ".into())] {
+            let context = HashMap::from([("ticket_num", Some("T-1".into())), ("link", link)]);
+            let actual = Ast::parse(template).unwrap().render(&context).unwrap();
+
+            assert_eq!(actual, "msg");
+        }
+
+        let actual = Ast::parse(template)
+            .unwrap()
+            .render(&HashMap::new())
+            .unwrap();
+        assert_eq!(actual, "msg");
+    }
+
+    #[test]
+    fn else_section_is_rendered_when_value_is_missing() {
+        let template = "{#if ticket_num}[{ticket_num}]{#else}[NO-TICKET]{/if} {message}";
+
+        let context = HashMap::from([("ticket_num", None), ("message", Some("msg".into()))]);
+        let actual = Ast::parse(template).unwrap().render(&context).unwrap();
+
+        assert_eq!(actual, "[NO-TICKET] msg");
+    }
+
+    #[test]
+    fn invalid_sections_fail_to_parse() {
+        let error = Ast::parse("{#if link}Issue: {link}").unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "Section \"{#if link}\" is missing a closing \"{/if}\""
+        );
+    }
+
+    #[test]
+    fn placeholder_filters_are_applied_to_values() {
+        let context = HashMap::from([
+            ("scope", Some("Core".into())),
+            ("ticket_num", Some("abc-12".into())),
+            ("message", Some("Add a login page".into())),
+        ]);
+
+        let actual = Ast::parse("[{ticket_num|upper}] ({scope|lower}) {message|slug|truncate:9}")
+            .unwrap()
+            .render(&context)
+            .unwrap();
+
+        assert_eq!(actual, "[ABC-12] (core) add-a-log");
+    }
+
+    #[test]
+    fn placeholder_filtered_to_empty_is_removed() {
+        let context = HashMap::from([
+            ("scope", Some("!!".into())),
+            ("message", Some("msg".into())),
+        ]);
+
+        let actual = Ast::parse("({scope|slug}) {message}")
+            .unwrap()
+            .render(&context)
+            .unwrap();
+
+        assert_eq!(actual, "msg");
+    }
+
+    #[test]
+    fn unknown_placeholders_with_filters_are_left_as_is() {
+        let actual = Ast::parse("{unknown|upper}")
+            .unwrap()
+            .render(&HashMap::new())
+            .unwrap();
+
+        assert_eq!(actual, "{unknown|upper}");
+    }
+
+    #[test]
+    fn placeholder_default_is_used_when_value_is_empty() {
+        for scope in [None, Some("".into()), Some("  ".into())] {
+            let context = HashMap::from([("scope", scope), ("message", Some("msg".into()))]);
+
+            let actual = Ast::parse("fix({scope:core|upper}): {message}")
+                .unwrap()
+                .render(&context)
+                .unwrap();
+
+            assert_eq!(actual, "fix(CORE): msg");
+        }
+    }
+
+    #[test]
+    fn placeholder_default_is_ignored_when_value_exists() {
+        let context = HashMap::from([("scope", Some("parser".into()))]);
+
+        let actual = Ast::parse("{scope:core}")
+            .unwrap()
+            .render(&context)
+            .unwrap();
+
+        assert_eq!(actual, "parser");
+    }
+
+    #[test]
+    fn required_placeholder_without_a_value_errors() {
+        let context = HashMap::from([("ticket_num", None), ("message", Some("msg".into()))]);
+
+        let error = Ast::parse("[{ticket_num!}] {message}")
+            .unwrap()
+            .render(&context)
+            .unwrap_err();
+
+        assert!(matches!(
+            error,
+            UserInputError::Required { name } if name == "ticket_num"
+        ));
+    }
+
+    #[test]
+    fn required_placeholder_within_a_skipped_section_does_not_error() {
+        let context = HashMap::from([("link", None), ("ticket_num", None)]);
+
+        let actual = Ast::parse("msg{#if link} {ticket_num!}{/if}")
+            .unwrap()
+            .render(&context)
+            .unwrap();
+
+        assert_eq!(actual, "msg");
+    }
+
+    #[test]
+    fn escaped_braces_are_rendered_as_literal_text() {
+        let context = HashMap::from([("message", Some("msg".into()))]);
+
+        let actual = Ast::parse("{message} \\{message\\} \\{\"a\": 1\\}")
+            .unwrap()
+            .render(&context)
+            .unwrap();
+
+        assert_eq!(actual, "msg {message} {\"a\": 1}");
+    }
+
+    #[test]
+    fn escaped_braces_are_never_removed_as_wrappers_or_joiners() {
+        let templates = [
+            ("\\{{target}\\}", "{}"),
+            ("\\{{target}}", "{}"),
+            ("{{target}\\}", "{}"),
+            ("[\\{{target}\\}]", "[{}]"),
+            ("{{target}}\\{\\}", "{}"),
+            ("\\{ {target} \\}", "{ }"),
+            ("-{target}\\}", "}"),
+        ];
+
+        for (template, expected) in templates {
+            assert_eq!(render_empty(template), expected, "{template}");
+        }
+    }
+
+    #[test]
+    fn escaped_braces_survive_partial_includes() {
+        let partials = HashMap::from([("json".into(), "\\{\"id\": \"{ticket_num}\"\\}".into())]);
+
+        let content = include_partials("{message} {> json}", &partials).unwrap();
+        let actual = Ast::parse(&content)
+            .unwrap()
+            .render(&HashMap::from([
+                ("message", Some("msg".into())),
+                ("ticket_num", Some("T-1".into())),
+            ]))
+            .unwrap();
+
+        assert_eq!(actual, "msg {\"id\": \"T-1\"}");
+    }
+
+    #[test]
+    fn names_are_collected_from_placeholders_and_sections() {
+        let ast = Ast::parse("{a} {#if b}{c}{#else}{env.D}{/if}").unwrap();
+
+        assert_eq!(ast.names(), vec!["a", "b", "c", "env.D"]);
+    }
+
+    #[test]
+    fn environment_variables_are_resolved_when_referenced() {
+        std::env::set_var("INKAN_TEMPLATE_TEST_VAR", "value");
+
+        let ast =
+            Ast::parse("{env.INKAN_TEMPLATE_TEST_VAR} {env.INKAN_TEMPLATE_TEST_MISSING}").unwrap();
+        let environment = ast.environment();
+
+        assert_eq!(
+            environment,
+            HashMap::from([
+                ("env.INKAN_TEMPLATE_TEST_VAR", Some("value".into())),
+                ("env.INKAN_TEMPLATE_TEST_MISSING", None),
+            ])
+        );
+        assert_eq!(ast.render(&environment).unwrap(), "value");
+    }
+
+    #[test]
+    fn partials_are_included_by_name() {
+        let partials = HashMap::from([
+            ("issue".into(), "Issue: {ticket_num} {>link}".into()),
+            ("link".into(), "{link}".into()),
+        ]);
+
+        let actual = include_partials("{message}\n\n{#if ticket_num}{> issue}{/if}", &partials);
+
+        assert_eq!(
+            actual.unwrap(),
+            "{message}\n\n{#if ticket_num}Issue: {ticket_num} {link}{/if}"
+        );
+    }
+
+    #[test]
+    fn unknown_partials_error() {
+        let error = include_partials("{> issue}", &HashMap::new()).unwrap_err();
+        assert_eq!(
+            error,
+            TemplateError::UnknownPartial {
+                name: "issue".into()
+            }
+        );
+
+        let error = Ast::parse("{> issue}").unwrap_err();
+        assert_eq!(
+            error,
+            TemplateError::UnknownPartial {
+                name: "issue".into()
+            }
+        );
+    }
+
+    #[test]
+    fn recursive_partials_error() {
+        let partials = HashMap::from([("a".into(), "{>b}".into()), ("b".into(), "{>a}".into())]);
+
+        let error = include_partials("{>a}", &partials).unwrap_err();
+
+        assert_eq!(error, TemplateError::RecursivePartial { name: "a".into() });
+    }
+
+    #[test]
+    fn partials_can_be_included_more_than_once() {
+        let partials = HashMap::from([("sep".into(), " | ".into())]);
+
+        let actual = include_partials("a{>sep}b{>sep}c", &partials).unwrap();
+
+        assert_eq!(actual, "a | b | c");
+    }
+}
@@ -0,0 +1,307 @@
+use std::fmt;
+
+use crate::domain::errors::TemplateError;
+
+use super::{filter::Filter, lexer::Token};
+
+/// Node of a parsed template.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Node {
+    /// Literal text copied into the output as is.
+    Text(String),
+    /// Value to be injected from the template context.
+    Placeholder(Placeholder),
+    /// Section only rendered when the named context value exists.
+    Conditional(Conditional),
+    /// Escaped literal text never altered by the cleanup rules.
+    Escaped(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Placeholder {
+    /// Name used to look up the value within the template context i.e `ticket_num`.
+    pub name: String,
+    /// Value must be provided otherwise rendering fails i.e `{ticket_num!}`.
+    pub required: bool,
+    /// Fallback used when the context value is empty i.e `{scope:core}`.
+    pub default: Option<String>,
+    /// Filters applied in order to the context value i.e `{scope|lower}`.
+    pub filters: Vec<Filter>,
+}
+
+impl Placeholder {
+    pub fn parse(expression: &str) -> Result<Placeholder, TemplateError> {
+        let mut parts = expression.split('|');
+        let declaration = parts.next().unwrap_or_default();
+
+        let (name, default) = match declaration.split_once(':') {
+            Some((name, default)) => (name, Some(default.to_string())),
+            None => (declaration, None),
+        };
+
+        let (name, required) = match name.strip_suffix('!') {
+            Some(name) => (name, true),
+            None => (name, false),
+        };
+
+        let filters = parts.map(Filter::parse).collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Placeholder {
+            name: name.trim().into(),
+            required,
+            default,
+            filters,
+        })
+    }
+}
+
+impl fmt::Display for Placeholder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{{{}", self.name)?;
+
+        if self.required {
+            write!(f, "!")?;
+        }
+
+        if let Some(default) = &self.default {
+            write!(f, ":{default}")?;
+        }
+
+        for filter in &self.filters {
+            write!(f, "|{filter}")?;
+        }
+
+        write!(f, "}}")
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Conditional {
+    /// Name of the context value the section depends on i.e `link`.
+    pub name: String,
+    /// Nodes rendered when the context value is present.
+    pub then: Vec<Node>,
+    /// Nodes rendered when the context value is missing or empty.
+    pub otherwise: Vec<Node>,
+}
+
+pub fn parse(tokens: Vec<Token>) -> Result<Vec<Node>, TemplateError> {
+    let mut tokens = tokens.into_iter();
+
+    match parse_nodes(&mut tokens)? {
+        (nodes, None) => Ok(nodes),
+        (_, Some(token)) => Err(TemplateError::Unexpected {
+            token: token.to_string(),
+        }),
+    }
+}
+
+/// Parse nodes until the end of the tokens or a token closing the current section is found.
+fn parse_nodes<I: Iterator<Item = Token>>(
+    tokens: &mut I,
+) -> Result<(Vec<Node>, Option<Token>), TemplateError> {
+    let mut nodes = vec![];
+
+    while let Some(token) = tokens.next() {
+        match token {
+            Token::Text(text) => nodes.push(Node::Text(text)),
+            Token::Escaped(c) => nodes.push(Node::Escaped(c.into())),
+            Token::Placeholder(expression) => {
+                nodes.push(Node::Placeholder(Placeholder::parse(&expression)?))
+            }
+            Token::If(name) => nodes.push(Node::Conditional(parse_conditional(name, tokens)?)),
+            Token::Else | Token::EndIf => return Ok((nodes, Some(token))),
+            // Partials are expected to be included before parsing.
+            Token::Include(name) => return Err(TemplateError::UnknownPartial { name }),
+        }
+    }
+
+    Ok((nodes, None))
+}
+
+fn parse_conditional<I: Iterator<Item = Token>>(
+    name: String,
+    tokens: &mut I,
+) -> Result<Conditional, TemplateError> {
+    let unclosed = || TemplateError::Unclosed {
+        section: Token::If(name.clone()).to_string(),
+    };
+
+    let (then, end) = parse_nodes(tokens)?;
+
+    let otherwise = match end {
+        Some(Token::EndIf) => vec![],
+        Some(Token::Else) => match parse_nodes(tokens)? {
+            (otherwise, Some(Token::EndIf)) => otherwise,
+            (_, Some(token)) => {
+                return Err(TemplateError::Unexpected {
+                    token: token.to_string(),
+                })
+            }
+            (_, None) => return Err(unclosed()),
+        },
+        _ => return Err(unclosed()),
+    };
+
+    Ok(Conditional {
+        name,
+        then,
+        otherwise,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::template::lexer::tokenize;
+
+    #[test]
+    fn tokens_are_mapped_into_nodes() {
+        let nodes = parse(vec![
+            Token::Text("[".into()),
+            Token::Placeholder("ticket_num".into()),
+            Token::Text("]".into()),
+        ])
+        .unwrap();
+
+        assert_eq!(
+            nodes,
+            vec![
+                Node::Text("[".into()),
+                Node::Placeholder(Placeholder {
+                    name: "ticket_num".into(),
+                    required: false,
+                    default: None,
+                    filters: vec![],
+                }),
+                Node::Text("]".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn escaped_tokens_are_mapped_into_escaped_nodes() {
+        let nodes = parse(tokenize("\\{a\\}")).unwrap();
+
+        assert_eq!(
+            nodes,
+            vec![
+                Node::Escaped("{".into()),
+                Node::Text("a".into()),
+                Node::Escaped("}".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn placeholder_filters_are_parsed_in_order() {
+        let nodes = parse(tokenize("{message|lower|truncate:72}")).unwrap();
+
+        assert_eq!(
+            nodes,
+            vec![Node::Placeholder(Placeholder {
+                name: "message".into(),
+                required: false,
+                default: None,
+                filters: vec![Filter::Lower, Filter::Truncate(72)],
+            })]
+        );
+    }
+
+    #[test]
+    fn placeholder_default_and_required_marker_are_parsed() {
+        let nodes = parse(tokenize("{scope:core services|upper}{ticket_num!}")).unwrap();
+
+        assert_eq!(
+            nodes,
+            vec![
+                Node::Placeholder(Placeholder {
+                    name: "scope".into(),
+                    required: false,
+                    default: Some("core services".into()),
+                    filters: vec![Filter::Upper],
+                }),
+                Node::Placeholder(Placeholder {
+                    name: "ticket_num".into(),
+                    required: true,
+                    default: None,
+                    filters: vec![],
+                }),
+            ]
+        );
+    }
+
+    #[test]
+    fn placeholder_display_round_trips_the_expression() {
+        for expression in [
+            "{scope}",
+            "{ticket_num!}",
+            "{scope:core|lower}",
+            "{a!:b|wrap:72}",
+        ] {
+            let placeholder = Placeholder::parse(&expression[1..expression.len() - 1]).unwrap();
+
+            assert_eq!(placeholder.to_string(), expression);
+        }
+    }
+
+    #[test]
+    fn placeholder_with_unknown_filter_errors() {
+        let error = parse(tokenize("{scope|shout}")).unwrap_err();
+
+        assert_eq!(
+            error,
+            TemplateError::UnknownFilter {
+                name: "shout".into()
+            }
+        );
+    }
+
+    #[test]
+    fn nested_conditional_sections_are_parsed_into_a_tree() {
+        let nodes = parse(tokenize("{#if a}A{#if b}B{/if}{#else}C{/if}")).unwrap();
+
+        assert_eq!(
+            nodes,
+            vec![Node::Conditional(Conditional {
+                name: "a".into(),
+                then: vec![
+                    Node::Text("A".into()),
+                    Node::Conditional(Conditional {
+                        name: "b".into(),
+                        then: vec![Node::Text("B".into())],
+                        otherwise: vec![],
+                    }),
+                ],
+                otherwise: vec![Node::Text("C".into())],
+            })]
+        );
+    }
+
+    #[test]
+    fn unclosed_conditional_section_errors() {
+        for content in ["{#if link}", "{#if link}{#else}", "{#if a}{#if link}{/if}"] {
+            let error = parse(tokenize(content)).unwrap_err();
+
+            assert!(matches!(error, TemplateError::Unclosed { .. }), "{content}");
+        }
+    }
+
+    #[test]
+    fn section_tokens_without_an_opening_if_errors() {
+        for (content, expected) in [
+            ("{/if}", "{/if}"),
+            ("{#else}", "{#else}"),
+            ("{#if a}{#else}{#else}{/if}", "{#else}"),
+        ] {
+            let error = parse(tokenize(content)).unwrap_err();
+
+            assert_eq!(
+                error,
+                TemplateError::Unexpected {
+                    token: expected.into()
+                }
+            );
+        }
+    }
+}
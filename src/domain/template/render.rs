@@ -0,0 +1,234 @@
+use std::collections::HashMap;
+
+use crate::{domain::errors::UserInputError, utils::string::OptionStr};
+
+use super::parser::{Node, Placeholder};
+
+/// Brackets that are removed along with an empty placeholder they directly wrap.
+const WRAPPERS: [(char, char); 3] = [('[', ']'), ('(', ')'), ('{', '}')];
+
+/// Joiners that would otherwise be left hanging once an empty placeholder is removed.
+const JOINERS: [char; 3] = ['-', '_', '/'];
+
+#[derive(Debug)]
+enum Segment {
+    /// Literal template text, the only segment the cleanup rules may alter.
+    Text(String),
+    /// Injected context value or escaped text which is never altered by the cleanup rules.
+    Value(String),
+    /// Placeholder without a value.
+    Removed,
+}
+
+pub fn render(
+    nodes: &[Node],
+    context: &HashMap<&str, Option<String>>,
+) -> Result<String, UserInputError> {
+    let mut segments = vec![];
+    push_segments(&mut segments, nodes, context)?;
+
+    for index in 0..segments.len() {
+        if matches!(segments[index], Segment::Removed) {
+            remove_surrounding(&mut segments, index);
+        }
+    }
+
+    let rendered: String = segments
+        .into_iter()
+        .map(|segment| match segment {
+            Segment::Text(text) | Segment::Value(text) => text,
+            Segment::Removed => "".into(),
+        })
+        .collect();
+
+    Ok(rendered.trim().into())
+}
+
+fn push_segments(
+    segments: &mut Vec<Segment>,
+    nodes: &[Node],
+    context: &HashMap<&str, Option<String>>,
+) -> Result<(), UserInputError> {
+    for node in nodes {
+        match node {
+            Node::Text(text) => segments.push(Segment::Text(text.clone())),
+            Node::Escaped(text) => segments.push(Segment::Value(text.clone())),
+            Node::Placeholder(placeholder) => {
+                segments.push(placeholder_segment(placeholder, context)?)
+            }
+            Node::Conditional(conditional) => {
+                let exists = context
+                    .get(conditional.name.as_str())
+                    .and_then(|value| value.none_if_empty())
+                    .is_some();
+
+                log::info!(
+                    "conditional section '{}' is {}",
+                    conditional.name,
+                    if exists { "rendered" } else { "skipped" }
+                );
+
+                let section = if exists {
+                    &conditional.then
+                } else {
+                    &conditional.otherwise
+                };
+
+                push_segments(segments, section, context)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn placeholder_segment(
+    placeholder: &Placeholder,
+    context: &HashMap<&str, Option<String>>,
+) -> Result<Segment, UserInputError> {
+    let name = &placeholder.name;
+
+    // Unknown placeholders are left as is for other tools to handle.
+    let Some(value) = context.get(name.as_str()) else {
+        return Ok(Segment::Value(placeholder.to_string()));
+    };
+
+    let value = value
+        .none_if_empty()
+        .or_else(|| placeholder.default.clone().none_if_empty());
+
+    let segment = match apply_filters(placeholder, value) {
+        Some(value) => {
+            log::info!("replace '{}' from template with '{}'", name, value);
+            Segment::Value(value)
+        }
+        None if placeholder.required => {
+            log::error!("required '{}' is missing from template context", name);
+            return Err(UserInputError::Required { name: name.clone() });
+        }
+        None => {
+            log::info!("removing '{}' from template", name);
+            Segment::Removed
+        }
+    };
+
+    Ok(segment)
+}
+
+fn apply_filters(placeholder: &Placeholder, value: Option<String>) -> Option<String> {
+    let value = placeholder
+        .filters
+        .iter()
+        .fold(value?, |value, filter| filter.apply(&value));
+
+    value.none_if_empty()
+}
+
+/// Tidy up the literal text either side of a removed placeholder, in order:
+/// 1. Wrapping brackets i.e `[{target}]` are removed.
+/// 2. Matching joiners either side i.e `hi-{target}-bye` are squashed into one.
+/// 3. Any other joiner directly before or after i.e `hi-{target}` is removed.
+/// 4. A single space directly after i.e `{target} bye` is removed.
+fn remove_surrounding(segments: &mut [Segment], index: usize) {
+    // Strip wrappers first so rules order are not as important making matches easier.
+    while let (Some(before), Some(after)) =
+        (text_before(segments, index), text_after(segments, index))
+    {
+        let (open, close) = (last_char(segments, before), first_char(segments, after));
+        let wrapped = WRAPPERS
+            .iter()
+            .any(|wrapper| (open, close) == (Some(wrapper.0), Some(wrapper.1)));
+
+        if !wrapped {
+            break;
+        }
+
+        pop_last(segments, before);
+        pop_first(segments, after);
+    }
+
+    let before = text_before(segments, index);
+    let after = text_after(segments, index);
+
+    let joiner_before = before.filter(|i| is_joiner(last_char(segments, *i)));
+    let joiner_after = after.filter(|i| is_joiner(first_char(segments, *i)));
+
+    match (joiner_before, joiner_after) {
+        // Keep one of the joiners to prevent squashing "hi-{target}-bye" => "hibye"
+        (Some(before), Some(after))
+            if last_char(segments, before) == first_char(segments, after) =>
+        {
+            pop_last(segments, before)
+        }
+        (before, after) => {
+            if let Some(before) = before {
+                pop_last(segments, before);
+            }
+
+            if let Some(after) = after {
+                pop_first(segments, after);
+            }
+        }
+    }
+
+    if let Some(after) = text_after(segments, index) {
+        if first_char(segments, after) == Some(' ') {
+            pop_first(segments, after);
+        }
+    }
+}
+
+/// Closest literal text before the index skipping over any removed or emptied segments.
+fn text_before(segments: &[Segment], index: usize) -> Option<usize> {
+    (0..index)
+        .rev()
+        .find(|i| !is_blank(&segments[*i]))
+        .filter(|i| matches!(segments[*i], Segment::Text(_)))
+}
+
+/// Closest literal text after the index skipping over any removed or emptied segments.
+fn text_after(segments: &[Segment], index: usize) -> Option<usize> {
+    (index + 1..segments.len())
+        .find(|i| !is_blank(&segments[*i]))
+        .filter(|i| matches!(segments[*i], Segment::Text(_)))
+}
+
+fn is_joiner(c: Option<char>) -> bool {
+    c.map(|c| JOINERS.contains(&c)).unwrap_or(false)
+}
+
+fn is_blank(segment: &Segment) -> bool {
+    match segment {
+        Segment::Removed => true,
+        Segment::Text(text) => text.is_empty(),
+        Segment::Value(_) => false,
+    }
+}
+
+fn last_char(segments: &[Segment], index: usize) -> Option<char> {
+    match &segments[index] {
+        Segment::Text(text) => text.chars().last(),
+        _ => None,
+    }
+}
+
+fn first_char(segments: &[Segment], index: usize) -> Option<char> {
+    match &segments[index] {
+        Segment::Text(text) => text.chars().next(),
+        _ => None,
+    }
+}
+
+fn pop_last(segments: &mut [Segment], index: usize) {
+    if let Segment::Text(text) = &mut segments[index] {
+        text.pop();
+    }
+}
+
+fn pop_first(segments: &mut [Segment], index: usize) {
+    if let Segment::Text(text) = &mut segments[index] {
+        if let Some(first) = text.chars().next() {
+            text.replace_range(..first.len_utf8(), "");
+        }
+    }
+}
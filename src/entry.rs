@@ -1,11 +1,14 @@
 use std::fmt::Debug;
 
-use crate::adapters::migrations::{db_migrations, DefaultConfig, MigrationContext};
+use crate::adapters::migrations::{
+    backfill_branch_repos, db_migrations, DefaultConfig, MigrationContext,
+};
 use crate::adapters::sqlite::Sqlite;
 use crate::adapters::{Git, GitCommand};
 use crate::app_context::AppContext;
 use crate::app_template::AppTemplate;
 use crate::cli::{commands::Commands, log::LogLevel};
+use crate::domain::adapters::Git as _;
 use anyhow::Ok;
 use clap::{Parser, ValueEnum};
 use directories::ProjectDirs;
@@ -63,10 +66,15 @@ impl Cli {
                     default: config_dir.join("default.yml"),
                     conventional: config_dir.join("conventional.yml"),
                 }),
-                version: 4,
+                version: 5,
             },
         )?;
 
+        // Outside of a git repository there are no branches to claim.
+        if let Result::Ok(repo) = git.repository_name() {
+            backfill_branch_repos(&connection, &repo)?;
+        }
+
         let store = Sqlite::new(connection);
         let app_config = AppTemplate::new(self.config.clone(), &git, &store)?;
         let context = AppContext::new(git, store, app_config.config, self.prompt.clone())?;
@@ -13,6 +13,7 @@ fn main() -> anyhow::Result<()> {
 
     if let Err(error) = result {
         display_error(error)?;
+        std::process::exit(1);
     }
 
     Ok(())
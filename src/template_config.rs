@@ -1,82 +1,799 @@
-use std::collections::HashMap;
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt,
+    path::Path,
+};
 
-use serde::{Deserialize, Serialize};
+use indexmap::IndexMap;
+use regex::Regex;
+use schemars::{
+    gen::{SchemaGenerator, SchemaSettings},
+    schema::{InstanceType, RootSchema, Schema, SchemaObject},
+    visit::{visit_schema_object, Visitor},
+    JsonSchema,
+};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_yaml::Value;
 
 use crate::{
     domain::{
-        errors::{Errors, UserInputError},
-        models::path::AbsolutePath,
+        adapters::Store,
+        errors::{ConfigError, Errors, TemplateError, UserInputError},
+        models::{self, path::AbsolutePath, ConfigKey},
+        template::include_partials,
     },
     utils::get_file_contents,
 };
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+/// Config file versions this release is able to read.
+pub const SUPPORTED_VERSIONS: [u32; 2] = [1, 2];
+
+/// Newest config layout, older versions can be upgraded via `inkan config migrate`.
+///
+/// - `1` any unknown keys such as `commit.name` are silently ignored.
+/// - `2` unknown keys are rejected so typos i.e `descripton` are no longer ignored.
+pub const LATEST_VERSION: u32 = 2;
+
+/// Context variables provided by the cli which cannot be declared as user defined variables.
+pub const BUILT_IN_VARIABLES: [&str; 10] = [
+    "ticket_num",
+    "scope",
+    "link",
+    "message",
+    "branch_name",
+    "repo",
+    "author_name",
+    "author_email",
+    "date",
+    "short_sha_parent",
+];
+
+/// Config file names discovered within a repository directory in order of priority.
+pub const LOCAL_CONFIG_FILES: [&str; 3] = [".inkan.yml", ".inkan.toml", ".inkan.json"];
+
+/// Nearest local config file within the directory or any of its parents, the search stops at the repository root.
+pub fn find_local_config(dir: &Path) -> Option<AbsolutePath> {
+    for dir in dir.ancestors() {
+        let config = LOCAL_CONFIG_FILES
+            .iter()
+            .map(|filename| dir.join(filename))
+            .find(|path| path.is_file())
+            .and_then(|path| path.try_into().ok());
+
+        if config.is_some() {
+            return config;
+        }
+
+        if dir.join(".git").exists() {
+            break;
+        }
+    }
+
+    None
+}
+
+/// Local config within a parent directory a nested local config is merged on top of.
+fn parent_local_config(path: &AbsolutePath) -> Option<AbsolutePath> {
+    let dir = path.as_ref().parent()?;
+
+    if dir.join(".git").exists() {
+        return None;
+    }
+
+    find_local_config(dir.parent()?)
+}
+
+/// File format of a config picked from the file extension, defaulting to yaml.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigFormat {
+    Yaml,
+    Toml,
+    Json,
+}
+
+impl ConfigFormat {
+    pub fn from_path(path: &Path) -> ConfigFormat {
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some("toml") => ConfigFormat::Toml,
+            Some("json") => ConfigFormat::Json,
+            _ => ConfigFormat::Yaml,
+        }
+    }
+
+    /// Deserialize the contents reporting any errors with the line & column they occurred.
+    pub fn deserialize<T: DeserializeOwned>(&self, contents: &str) -> Result<T, ConfigError> {
+        let (message, position) = match self {
+            ConfigFormat::Yaml => match serde_yaml::from_str(contents) {
+                Ok(value) => return Ok(value),
+                Err(e) => {
+                    let position = e.location().map(|l| (l.line(), l.column()));
+                    (e.to_string(), position)
+                }
+            },
+            ConfigFormat::Toml => match toml::from_str(contents) {
+                Ok(value) => return Ok(value),
+                Err(e) => {
+                    let position = e.span().map(|span| line_column(contents, span.start));
+                    (e.message().to_string(), position)
+                }
+            },
+            ConfigFormat::Json => match serde_json::from_str(contents) {
+                Ok(value) => return Ok(value),
+                Err(e) => {
+                    let position = Some((e.line(), e.column()));
+                    (e.to_string(), position)
+                }
+            },
+        };
+
+        // Yaml & json errors already include the position within the message.
+        let message = match (self, position) {
+            (ConfigFormat::Toml, Some((line, column))) => {
+                format!("{message} at line {line} column {column}")
+            }
+            _ => message,
+        };
+
+        Err(ConfigError::Invalid {
+            message: format!("{self} {message}"),
+        })
+    }
+}
+
+impl fmt::Display for ConfigFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigFormat::Yaml => write!(f, "yaml"),
+            ConfigFormat::Toml => write!(f, "toml"),
+            ConfigFormat::Json => write!(f, "json"),
+        }
+    }
+}
+
+/// Config file merged into the final config, any configs it extends are merged first.
+#[derive(Debug, Clone)]
+pub struct ConfigLayer {
+    /// Config the layer was loaded from i.e `conventional` or `local`.
+    pub key: ConfigKey,
+    pub path: AbsolutePath,
+    pub format: ConfigFormat,
+    pub contents: String,
+}
+
+/// One based line & column of a byte offset within the contents.
+fn line_column(contents: &str, offset: usize) -> (usize, usize) {
+    let before = &contents[..offset.min(contents.len())];
+    let line = before.matches('\n').count() + 1;
+    let column = before.chars().rev().take_while(|c| *c != '\n').count() + 1;
+
+    (line, column)
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, JsonSchema)]
 pub struct TemplateConfig {
+    /// Layout version the config is written in.
+    #[schemars(schema_with = "version_schema")]
     pub version: u32,
+    /// Name of a built in or added config this config is merged on top of i.e `conventional`.
+    pub extends: Option<String>,
+    /// User defined context variables that can be set per branch i.e `component`.
+    #[serde(default)]
+    pub variables: Vec<String>,
+    /// Template used to build branch names on checkout.
     pub branch: Option<BranchConfig>,
     pub commit: CommitConfig,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, JsonSchema)]
 pub struct BranchConfig {
+    /// Branch name template i.e `{branch_name}-{ticket_num}`.
     pub content: String,
+    /// Replaces any whitespace or characters git does not allow in branch names.
+    #[serde(default = "branch_separator")]
+    pub separator: String,
+    /// Branch names longer than this many characters are truncated.
+    pub max_length: Option<usize>,
+    /// Branch types selected from on checkout for the `{type}` placeholder i.e `feature`, `hotfix`.
+    #[serde(default)]
+    pub types: Vec<String>,
+    /// Ref new branches are created from unless `--from` is given i.e `origin/main`, otherwise the current `HEAD`.
+    pub base: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+impl BranchConfig {
+    pub const DEFAULT_SEPARATOR: &'static str = "-";
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, JsonSchema)]
 pub struct CommitConfig {
-    pub templates: HashMap<String, Template>,
+    /// Content prepended to every template unless the template opts out.
+    pub header: Option<String>,
+    /// Content appended to every template unless the template opts out.
+    pub footer: Option<String>,
+    /// Reusable content templates can include by name i.e `{> issue}`.
+    #[serde(default)]
+    pub partials: HashMap<String, String>,
+    /// Commit templates by name i.e `inkan commit fix`, listed in the order declared.
+    pub templates: IndexMap<String, Template>,
+    /// Template used by default when the current branch name matches, the first match is used.
+    #[serde(default)]
+    pub defaults: Vec<BranchDefault>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq, JsonSchema)]
+pub struct BranchDefault {
+    /// Regex pattern matched against the current branch name i.e `^hotfix/`.
+    pub branch: String,
+    /// Name or alias of the template used by default i.e `bug`.
+    pub template: String,
+}
+
+impl BranchDefault {
+    pub fn pattern(&self) -> Result<Regex, UserInputError> {
+        Regex::new(&self.branch).map_err(|e| UserInputError::Validation {
+            name: "defaults".into(),
+            message: format!("Invalid branch pattern '{}' {e}", self.branch),
+        })
+    }
+}
+
+impl CommitConfig {
+    /// Template content with the shared header, footer and any included partials applied.
+    pub fn content(&self, template: &Template) -> Result<String, TemplateError> {
+        let header = self.header.as_deref().filter(|_| template.header);
+        let footer = self.footer.as_deref().filter(|_| template.footer);
+
+        let content = format!(
+            "{}{}{}",
+            header.unwrap_or_default(),
+            template.content,
+            footer.unwrap_or_default()
+        );
+
+        include_partials(&content, &self.partials)
+    }
+
+    /// Template along with its name found by name or any of its aliases.
+    pub fn resolve(&self, name: &str) -> Option<(&String, &Template)> {
+        self.templates.get_key_value(name).or_else(|| {
+            self.templates
+                .iter()
+                .find(|(_, template)| template.aliases.iter().any(|alias| alias == name))
+        })
+    }
+
+    /// Template used by default for the branch name, from the first matching branch pattern.
+    pub fn default_template(&self, branch_name: &str) -> Result<Option<String>, UserInputError> {
+        for default in &self.defaults {
+            if default.pattern()?.is_match(branch_name) {
+                log::info!(
+                    "branch '{}' matches '{}' defaulting to template '{}'",
+                    branch_name,
+                    default.branch,
+                    default.template
+                );
+                return Ok(Some(default.template.clone()));
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Templates in the order listed, ordered templates come first followed by the rest in the order declared.
+    /// Templates sharing a group are kept together in the order each group is first listed.
+    pub fn ordered_templates(&self) -> Vec<(&String, &Template)> {
+        let mut templates = self.templates.iter().collect::<Vec<_>>();
+        templates.sort_by_key(|(_, template)| template.order.unwrap_or(u32::MAX));
+
+        let mut groups: Vec<Option<&str>> = vec![];
+        for (_, template) in &templates {
+            if !groups.contains(&template.group.as_deref()) {
+                groups.push(template.group.as_deref());
+            }
+        }
+
+        templates.sort_by_key(|(_, template)| {
+            groups
+                .iter()
+                .position(|group| *group == template.group.as_deref())
+        });
+
+        templates
+    }
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, JsonSchema)]
 pub struct Template {
+    /// Short description shown when listing templates.
     pub description: String,
+    /// Commit message template i.e `[{ticket_num}] {message}`.
     pub content: String,
+    /// Alternate names the template can be selected by i.e `bugfix`.
+    #[serde(default)]
+    pub aliases: Vec<String>,
+    /// Opt out of the shared commit header when `false`.
+    #[serde(default = "enabled")]
+    pub header: bool,
+    /// Opt out of the shared commit footer when `false`.
+    #[serde(default = "enabled")]
+    pub footer: bool,
+    /// Section the template is listed under i.e `Fixes`.
+    pub group: Option<String>,
+    /// Position the template is listed at, otherwise the order declared is used.
+    pub order: Option<u32>,
+    /// Questions asked in order when committing, otherwise the default ticket, scope & link prompts are used.
+    #[serde(default)]
+    pub prompts: Vec<PromptConfig>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq, JsonSchema)]
+pub struct PromptConfig {
+    /// Context variable the answer is injected into i.e `scope`.
+    pub name: String,
+    /// Question shown to the user, defaults to the name.
+    pub label: Option<String>,
+    /// Help message shown below the question.
+    pub help: Option<String>,
+    /// Answer used when nothing has been previously set for the branch.
+    pub default: Option<String>,
+    /// Regex pattern any non empty answer must match.
+    pub validate: Option<String>,
+    /// Restricts the answer to one of the choices via a select prompt.
+    #[serde(default)]
+    pub choices: Vec<String>,
+}
+
+fn enabled() -> bool {
+    true
+}
+
+fn branch_separator() -> String {
+    BranchConfig::DEFAULT_SEPARATOR.into()
+}
+
+fn version_schema(_: &mut SchemaGenerator) -> Schema {
+    Schema::Object(SchemaObject {
+        instance_type: Some(InstanceType::Integer.into()),
+        enum_values: Some(SUPPORTED_VERSIONS.iter().map(|v| (*v).into()).collect()),
+        ..Default::default()
+    })
+}
+
+/// Unknown keys are rejected so only the declared properties are allowed.
+#[derive(Debug, Clone)]
+struct DenyUnknownKeys;
+
+impl Visitor for DenyUnknownKeys {
+    fn visit_schema_object(&mut self, schema: &mut SchemaObject) {
+        if let Some(object) = &mut schema.object {
+            if !object.properties.is_empty() && object.additional_properties.is_none() {
+                object.additional_properties = Some(Box::new(Schema::Bool(false)));
+            }
+        }
+
+        visit_schema_object(self, schema);
+    }
+}
+
+fn raw_version(value: &Value) -> Option<u64> {
+    value.get("version").and_then(Value::as_u64)
+}
+
+fn merge_values(base: &mut Value, overlay: Value) {
+    match (base, overlay) {
+        (Value::Mapping(base), Value::Mapping(overlay)) => {
+            for (key, value) in overlay {
+                match base.get_mut(&key) {
+                    Some(existing) => merge_values(existing, value),
+                    None => {
+                        base.insert(key, value);
+                    }
+                }
+            }
+        }
+        (base, overlay) => *base = overlay,
+    }
+}
+
+fn collect_unknown_keys(raw: &Value, known: &Value, path: &str, keys: &mut Vec<String>) {
+    match (raw, known) {
+        (Value::Mapping(raw), Value::Mapping(known)) => {
+            for (key, value) in raw {
+                let name = match key {
+                    Value::String(name) => name.clone(),
+                    key => serde_yaml::to_string(key).unwrap_or_default().trim().into(),
+                };
+
+                let location = match path {
+                    "" => name,
+                    path => format!("{path}.{name}"),
+                };
+
+                match known.get(key) {
+                    Some(known) => collect_unknown_keys(value, known, &location, keys),
+                    None => keys.push(location),
+                }
+            }
+        }
+        (Value::Sequence(raw), Value::Sequence(known)) => {
+            for (index, (raw, known)) in raw.iter().zip(known).enumerate() {
+                collect_unknown_keys(raw, known, &format!("{path}[{index}]"), keys);
+            }
+        }
+        _ => {}
+    }
+}
+
+impl PromptConfig {
+    pub fn label(&self) -> String {
+        self.label.clone().unwrap_or_else(|| self.name.clone())
+    }
+
+    pub fn pattern(&self) -> Result<Option<Regex>, UserInputError> {
+        self.validate
+            .as_ref()
+            .map(|pattern| {
+                Regex::new(pattern).map_err(|e| UserInputError::Validation {
+                    name: self.name.clone(),
+                    message: format!("Invalid validate pattern '{pattern}' {e}"),
+                })
+            })
+            .transpose()
+    }
+
+    /// Validates an answer provided without prompting i.e via an argument.
+    pub fn validate(&self, answer: &str) -> Result<(), UserInputError> {
+        if !self.choices.is_empty() && !self.choices.iter().any(|choice| choice == answer) {
+            return Err(UserInputError::Validation {
+                name: self.name.clone(),
+                message: format!("Expected one of [{}]", self.choices.join(", ")),
+            });
+        }
+
+        match self.pattern()? {
+            Some(pattern) if !pattern.is_match(answer) => Err(UserInputError::Validation {
+                name: self.name.clone(),
+                message: format!("Expected to match the pattern '{pattern}'"),
+            }),
+            _ => Ok(()),
+        }
+    }
 }
 
 impl TemplateConfig {
-    pub fn new(config_path: &AbsolutePath) -> Result<Self, Errors> {
-        let config_contents =
-            get_file_contents(config_path).map_err(|e| Errors::Configuration {
-                message: format!(
-                    "Failed to read configuration at path '{}'",
-                    config_path.to_string()
-                ),
+    /// Load the config merged on top of any configs it `extends`.
+    pub fn load<S: Store>(config: &models::Template, store: &S) -> Result<Self, Errors> {
+        let layers = TemplateConfig::layers(config, store)?;
+
+        TemplateConfig::merge(&layers).map_err(|e| Errors::Configuration {
+            message: format!("Failed to parse configuration at path '{}'", config.path),
+            source: e.into(),
+        })
+    }
+
+    /// Config files in the order they are merged, starting with the config furthest extended.
+    pub fn layers<S: Store>(
+        config: &models::Template,
+        store: &S,
+    ) -> Result<Vec<ConfigLayer>, Errors> {
+        let mut layers: Vec<ConfigLayer> = vec![];
+        let mut next = Some((config.key.clone(), config.path.clone()));
+
+        while let Some((key, path)) = next {
+            if layers.iter().any(|layer| layer.path == path) {
+                return Err(Errors::Configuration {
+                    message: format!("Failed to extend configuration at path '{}'", path),
+                    source: ConfigError::CircularExtends {
+                        key: key.to_string(),
+                    }
+                    .into(),
+                });
+            }
+
+            let contents = get_file_contents(&path).map_err(|e| Errors::Configuration {
+                message: format!("Failed to read configuration at path '{}'", path),
                 source: e,
             })?;
+            let format = ConfigFormat::from_path(path.as_ref());
 
-        let config = serde_yaml::from_str::<TemplateConfig>(&config_contents).map_err(|e| {
-            Errors::Configuration {
-                message: format!(
-                    "Failed to parse configuration from please ensure yaml is valid.\n{}",
-                    config_path.to_string()
-                ),
-                source: e.into(),
+            let value = TemplateConfig::parse_value(&contents, format).map_err(|e| {
+                Errors::Configuration {
+                    message: format!("Failed to parse configuration at path '{}'", path),
+                    source: e.into(),
+                }
+            })?;
+
+            next = match value.get("extends").and_then(Value::as_str) {
+                Some(extends) => {
+                    log::info!("config '{}' extends '{}'", key, extends);
+                    let extended = store.get_template(Some(extends.into())).map_err(|e| {
+                        Errors::Configuration {
+                            message: format!(
+                                "Failed to find config '{}' extended by '{}'",
+                                extends, path
+                            ),
+                            source: e.into(),
+                        }
+                    })?;
+
+                    Some((extended.key, extended.path))
+                }
+                // Nested local configs i.e within a monorepo package are merged on top of any parent local config.
+                None if key == ConfigKey::Local => {
+                    parent_local_config(&path).map(|parent| (ConfigKey::Local, parent))
+                }
+                None => None,
+            };
+
+            layers.push(ConfigLayer {
+                key,
+                path,
+                format,
+                contents,
+            });
+        }
+
+        layers.reverse();
+
+        Ok(layers)
+    }
+
+    /// Deep merge the layers in order, maps are merged key by key while any other values such as lists are replaced.
+    pub fn merge(layers: &[ConfigLayer]) -> Result<TemplateConfig, ConfigError> {
+        // A single config is parsed directly so any errors keep their line & column.
+        if let [layer] = layers {
+            return TemplateConfig::parse(&layer.contents, layer.format);
+        }
+
+        let mut merged = Value::Null;
+        let mut values = vec![];
+
+        for layer in layers {
+            let value = TemplateConfig::parse_value(&layer.contents, layer.format)?;
+            merge_values(&mut merged, value.clone());
+            values.push(value);
+        }
+
+        let config: TemplateConfig =
+            serde_yaml::from_value(merged).map_err(|e| ConfigError::Invalid {
+                message: e.to_string(),
+            })?;
+
+        // Each layer is only held to the unknown keys rule of its own version.
+        let mut keys = values
+            .iter()
+            .filter(|value| raw_version(value).is_some_and(|version| version >= 2))
+            .flat_map(|value| config.unknown_keys(value))
+            .collect::<Vec<_>>();
+        keys.sort();
+        keys.dedup();
+
+        if !keys.is_empty() {
+            return Err(ConfigError::UnknownKeys { keys });
+        }
+
+        config.check_aliases()?.check_variables()
+    }
+
+    /// Layers declaring a value at the key path i.e `["commit", "templates", "fix"]` in the order they are merged,
+    /// the last layer being the one whose values win.
+    pub fn sources<'a>(
+        layers: &'a [ConfigLayer],
+        keys: &[&str],
+    ) -> Result<Vec<&'a ConfigLayer>, ConfigError> {
+        let mut sources = vec![];
+
+        for layer in layers {
+            let value = TemplateConfig::parse_value(&layer.contents, layer.format)?;
+            let declared = keys
+                .iter()
+                .try_fold(&value, |value, key| value.get(key))
+                .is_some_and(|value| !value.is_null());
+
+            if declared {
+                sources.push(layer);
+            }
+        }
+
+        Ok(sources)
+    }
+
+    /// Parse the raw config contents checking the version is supported.
+    pub fn parse(contents: &str, format: ConfigFormat) -> Result<TemplateConfig, ConfigError> {
+        let value = TemplateConfig::parse_value(contents, format)?;
+        let config = format.deserialize::<TemplateConfig>(contents)?;
+
+        let keys = config.unknown_keys(&value);
+        if config.rejects_unknown_keys() && !keys.is_empty() {
+            return Err(ConfigError::UnknownKeys { keys });
+        }
+
+        config.check_aliases()?.check_variables()
+    }
+
+    /// Parse the raw config contents into a generic value checking the version is supported.
+    fn parse_value(contents: &str, format: ConfigFormat) -> Result<Value, ConfigError> {
+        let value = format.deserialize::<Value>(contents)?;
+
+        if let Some(version) = raw_version(&value) {
+            if !SUPPORTED_VERSIONS.iter().any(|v| u64::from(*v) == version) {
+                return Err(ConfigError::UnsupportedVersion {
+                    version,
+                    supported: SUPPORTED_VERSIONS.to_vec(),
+                });
+            }
+
+            if version < u64::from(LATEST_VERSION) {
+                log::warn!(
+                    "config version {} is out of date run 'inkan config migrate' to upgrade",
+                    version
+                );
             }
-        })?;
+        }
+
+        Ok(value)
+    }
+
+    /// JSON Schema of the latest config layout generated from the config structs.
+    pub fn schema() -> RootSchema {
+        SchemaSettings::draft07()
+            .with_visitor(DenyUnknownKeys)
+            .into_generator()
+            .into_root_schema_for::<TemplateConfig>()
+    }
+
+    /// Unknown keys are rejected from version 2, older versions silently ignore them.
+    pub fn rejects_unknown_keys(&self) -> bool {
+        self.version >= 2
+    }
+
+    /// Keys found within the raw config that are not part of the config layout i.e `commit.name`.
+    pub fn unknown_keys(&self, raw: &Value) -> Vec<String> {
+        let known = serde_yaml::to_value(self).unwrap_or_default();
+
+        let mut keys = vec![];
+        collect_unknown_keys(raw, &known, "", &mut keys);
+        keys.sort();
 
-        Ok(config)
+        keys
     }
 
+    /// Template by name or any of its aliases.
     pub fn get_template_config(&self, name: &str) -> Result<&Template, UserInputError> {
         log::info!("fetching template {}", name);
-        let template = self
-            .commit
-            .templates
-            .get(name)
-            .ok_or_else(|| UserInputError::InvalidCommand { name: name.into() })?;
+        let (_, template) =
+            self.commit
+                .resolve(name)
+                .ok_or_else(|| UserInputError::InvalidCommand {
+                    name: name.into(),
+                    valid: self
+                        .commit
+                        .templates
+                        .iter()
+                        .flat_map(|(name, template)| std::iter::once(name).chain(&template.aliases))
+                        .cloned()
+                        .collect(),
+                })?;
 
         Ok(template)
     }
+
+    /// Template names & aliases claimed by more than one template along with the templates claiming them.
+    pub fn alias_collisions(&self) -> BTreeMap<String, Vec<String>> {
+        let mut claimed: BTreeMap<String, Vec<String>> = BTreeMap::new();
+
+        for (name, template) in &self.commit.templates {
+            for claim in std::iter::once(name).chain(&template.aliases) {
+                claimed.entry(claim.clone()).or_default().push(name.clone());
+            }
+        }
+
+        claimed.retain(|_, templates| templates.len() > 1);
+
+        claimed
+    }
+
+    fn check_aliases(self) -> Result<TemplateConfig, ConfigError> {
+        let collisions = self.alias_collisions();
+
+        if !collisions.is_empty() {
+            return Err(ConfigError::AliasCollision {
+                names: collisions.into_keys().collect(),
+            });
+        }
+
+        Ok(self)
+    }
+
+    /// Declared config variables that clash with the built in context variables i.e `scope`.
+    pub fn reserved_variables(&self) -> Vec<String> {
+        self.variables
+            .iter()
+            .filter(|name| BUILT_IN_VARIABLES.contains(&name.as_str()))
+            .cloned()
+            .collect()
+    }
+
+    fn check_variables(self) -> Result<TemplateConfig, ConfigError> {
+        let names = self.reserved_variables();
+
+        if !names.is_empty() {
+            return Err(ConfigError::ReservedVariables { names });
+        }
+
+        Ok(self)
+    }
+
+    /// Variables declared at the config level along with any prompted by the template.
+    pub fn declared_variables(&self, template: Option<&Template>) -> Vec<String> {
+        let prompted = template
+            .into_iter()
+            .flat_map(|template| template.prompts.iter())
+            .map(|prompt| &prompt.name)
+            .filter(|name| !BUILT_IN_VARIABLES.contains(&name.as_str()));
+
+        let mut declared = self.variables.clone();
+        for name in prompted {
+            if !declared.contains(name) {
+                declared.push(name.clone());
+            }
+        }
+
+        declared
+    }
+
+    pub fn validate_variables(
+        &self,
+        template: Option<&Template>,
+        variables: &BTreeMap<String, String>,
+    ) -> Result<(), UserInputError> {
+        let declared = self.declared_variables(template);
+        let undeclared = variables.keys().find(|name| !declared.contains(name));
+
+        if let Some(name) = undeclared {
+            log::error!("variable '{}' is not declared within the config", name);
+            return Err(UserInputError::Validation {
+                name: name.into(),
+                message: format!(
+                    "Expected one of the declared config variables [{}]",
+                    declared.join(", ")
+                ),
+            });
+        }
+
+        Ok(())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::{
-        domain::errors::UserInputError,
-        template_config::{CommitConfig, Template, TemplateConfig},
+        domain::errors::{ConfigError, UserInputError},
+        domain::models::ConfigKey,
+        template_config::{
+            CommitConfig, ConfigFormat, ConfigLayer, PromptConfig, Template, TemplateConfig,
+            LATEST_VERSION, SUPPORTED_VERSIONS,
+        },
     };
     use fake::{Fake, Faker};
-    use std::collections::HashMap;
+    use indexmap::IndexMap;
+    use regex::Regex;
+    use serde_json::json;
+    use std::{
+        collections::{BTreeMap, HashMap},
+        path::{Path, PathBuf},
+    };
+
+    fn layer(key: &str, contents: &str) -> ConfigLayer {
+        let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("Cargo.toml");
+
+        ConfigLayer {
+            key: key.into(),
+            path: path.try_into().unwrap(),
+            format: ConfigFormat::Yaml,
+            contents: contents.into(),
+        }
+    }
 
     #[test]
     fn get_template_config_by_name_key() {
@@ -84,15 +801,27 @@ mod tests {
 
         let config = TemplateConfig {
             commit: CommitConfig {
-                templates: HashMap::from([(
+                header: None,
+                footer: None,
+                partials: HashMap::new(),
+                defaults: vec![],
+                templates: IndexMap::from([(
                     key.clone(),
                     Template {
                         description: key.clone(),
                         content: key.clone(),
+                        aliases: vec![],
+                        header: true,
+                        footer: true,
+                        group: None,
+                        order: None,
+                        prompts: vec![],
                     },
                 )]),
             },
             version: 1,
+            extends: None,
+            variables: vec![],
             branch: None,
         };
 
@@ -107,16 +836,803 @@ mod tests {
 
         let config = TemplateConfig {
             commit: CommitConfig {
-                templates: HashMap::from([]),
+                header: None,
+                footer: None,
+                partials: HashMap::new(),
+                defaults: vec![],
+                templates: IndexMap::from([]),
             },
             version: 1,
+            extends: None,
+            variables: vec![],
             branch: None,
         };
 
         let result = config.get_template_config(&key).unwrap_err();
         assert!(matches!(
             result,
-            UserInputError::InvalidCommand { name } if name == key,
+            UserInputError::InvalidCommand { name, valid } if name == key && valid.is_empty(),
+        ));
+    }
+
+    #[test]
+    fn validate_variables_allows_declared_variables() {
+        let config = TemplateConfig {
+            commit: CommitConfig {
+                header: None,
+                footer: None,
+                partials: HashMap::new(),
+                defaults: vec![],
+                templates: IndexMap::new(),
+            },
+            version: 1,
+            extends: None,
+            variables: vec!["component".into(), "epic".into()],
+            branch: None,
+        };
+
+        let variables = BTreeMap::from([("component".into(), Faker.fake())]);
+
+        assert!(config.validate_variables(None, &variables).is_ok());
+    }
+
+    #[test]
+    fn validate_variables_errors_on_undeclared_variable() {
+        let config = TemplateConfig {
+            commit: CommitConfig {
+                header: None,
+                footer: None,
+                partials: HashMap::new(),
+                defaults: vec![],
+                templates: IndexMap::new(),
+            },
+            version: 1,
+            extends: None,
+            variables: vec!["component".into()],
+            branch: None,
+        };
+
+        let variables = BTreeMap::from([("reviewer".into(), Faker.fake())]);
+
+        let error = config.validate_variables(None, &variables).unwrap_err();
+        assert!(matches!(
+            error,
+            UserInputError::Validation { name, .. } if name == "reviewer",
         ));
     }
+
+    #[test]
+    fn validate_variables_allows_variables_prompted_by_the_template() {
+        let config = TemplateConfig {
+            commit: CommitConfig {
+                header: None,
+                footer: None,
+                partials: HashMap::new(),
+                defaults: vec![],
+                templates: IndexMap::new(),
+            },
+            version: 1,
+            extends: None,
+            variables: vec![],
+            branch: None,
+        };
+
+        let template = Template {
+            description: Faker.fake(),
+            content: Faker.fake(),
+            aliases: vec![],
+            header: true,
+            footer: true,
+            group: None,
+            order: None,
+            prompts: vec![fake_prompt("reviewer"), fake_prompt("scope")],
+        };
+
+        let variables = BTreeMap::from([("reviewer".into(), Faker.fake())]);
+
+        assert!(config
+            .validate_variables(Some(&template), &variables)
+            .is_ok());
+        assert_eq!(config.declared_variables(Some(&template)), vec!["reviewer"]);
+    }
+
+    #[test]
+    fn prompt_validate_checks_choices_and_pattern() {
+        let prompt = PromptConfig {
+            choices: vec!["api".into(), "cli".into()],
+            validate: Some("^[a-z]+$".into()),
+            ..fake_prompt("component")
+        };
+
+        assert!(prompt.validate("api").is_ok());
+        assert!(prompt.validate("web").is_err());
+
+        let prompt = PromptConfig {
+            choices: vec![],
+            ..prompt
+        };
+
+        assert!(prompt.validate("web").is_ok());
+        assert!(prompt.validate("Web").is_err());
+    }
+
+    #[test]
+    fn prompt_with_invalid_pattern_errors() {
+        let prompt = PromptConfig {
+            validate: Some("[a-z".into()),
+            ..fake_prompt("component")
+        };
+
+        let error = prompt.pattern().unwrap_err();
+
+        assert!(matches!(
+            error,
+            UserInputError::Validation { name, .. } if name == "component",
+        ));
+    }
+
+    fn fake_prompt(name: &str) -> PromptConfig {
+        PromptConfig {
+            name: name.into(),
+            label: Faker.fake(),
+            help: Faker.fake(),
+            default: Faker.fake(),
+            validate: None,
+            choices: vec![],
+        }
+    }
+
+    #[test]
+    fn commit_content_applies_header_footer_and_partials() {
+        let commit = CommitConfig {
+            header: Some("{> type}: ".into()),
+            footer: Some("\n\n{> issue}".into()),
+            partials: HashMap::from([
+                ("type".into(), "fix".into()),
+                ("issue".into(), "Issue: {ticket_num}".into()),
+            ]),
+            templates: IndexMap::new(),
+            defaults: vec![],
+        };
+
+        let template = Template {
+            description: Faker.fake(),
+            content: "{message}".into(),
+            aliases: vec![],
+            header: true,
+            footer: true,
+            group: None,
+            order: None,
+            prompts: vec![],
+        };
+
+        assert_eq!(
+            commit.content(&template).unwrap(),
+            "fix: {message}\n\nIssue: {ticket_num}"
+        );
+
+        let template = Template {
+            header: false,
+            ..template
+        };
+
+        assert_eq!(
+            commit.content(&template).unwrap(),
+            "{message}\n\nIssue: {ticket_num}"
+        );
+
+        let template = Template {
+            footer: false,
+            ..template
+        };
+
+        assert_eq!(commit.content(&template).unwrap(), "{message}");
+    }
+
+    #[test]
+    fn header_and_footer_are_applied_unless_the_template_opts_out() {
+        let config = serde_yaml::from_str::<TemplateConfig>(
+            r#"
+version: 1
+commit:
+  footer: "!"
+  templates:
+    default:
+      description: default
+      content: a
+    opt-out:
+      description: opt-out
+      content: b
+      footer: false
+"#,
+        )
+        .unwrap();
+
+        let content = |name: &str| {
+            let template = config.get_template_config(name).unwrap();
+            config.commit.content(template).unwrap()
+        };
+
+        assert_eq!(content("default"), "a!");
+        assert_eq!(content("opt-out"), "b");
+    }
+
+    #[test]
+    fn provided_templates_are_the_latest_version() {
+        for contents in [
+            include_str!("../templates/default.yml"),
+            include_str!("../templates/conventional.yml"),
+        ] {
+            let config = TemplateConfig::parse(contents, ConfigFormat::Yaml).unwrap();
+
+            assert_eq!(config.version, LATEST_VERSION);
+        }
+    }
+
+    #[test]
+    fn unsupported_versions_are_rejected() {
+        for version in [0, 3, 99] {
+            let error = TemplateConfig::parse(
+                &format!("version: {version}\ncommit:\n  templates: {{}}\n"),
+                ConfigFormat::Yaml,
+            )
+            .unwrap_err();
+
+            assert_eq!(
+                error,
+                ConfigError::UnsupportedVersion {
+                    version,
+                    supported: SUPPORTED_VERSIONS.to_vec()
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn unknown_keys_are_ignored_in_version_1() {
+        let config = TemplateConfig::parse(
+            "version: 1\ncommit:\n  name: old\n  templates:\n    fix:\n      descripton: typo\n      description: Fix\n      content: a\n",
+        ConfigFormat::Yaml,
+        )
+        .unwrap();
+
+        assert_eq!(config.version, 1);
+    }
+
+    #[test]
+    fn unknown_keys_are_rejected_from_version_2() {
+        let error = TemplateConfig::parse(
+            r#"version: 2
+unknown: true
+commit:
+  name: old
+  templates:
+    fix:
+      description: Fix
+      content: a
+      prompts:
+        - name: scope
+          lable: Scope
+"#,
+            ConfigFormat::Yaml,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            error,
+            ConfigError::UnknownKeys {
+                keys: vec![
+                    "commit.name".into(),
+                    "commit.templates.fix.prompts[0].lable".into(),
+                    "unknown".into(),
+                ]
+            }
+        );
+    }
+
+    #[test]
+    fn schema_lists_supported_versions_and_denies_unknown_keys() {
+        let schema = serde_json::to_value(TemplateConfig::schema()).unwrap();
+
+        assert_eq!(schema["properties"]["version"]["enum"], json!([1, 2]));
+        assert_eq!(schema["additionalProperties"], json!(false));
+
+        for definition in ["BranchConfig", "CommitConfig", "Template", "PromptConfig"] {
+            assert_eq!(
+                schema["definitions"][definition]["additionalProperties"],
+                json!(false),
+                "{definition}"
+            );
+        }
+
+        assert_eq!(
+            schema["definitions"]["CommitConfig"]["properties"]["templates"]
+                ["additionalProperties"],
+            json!({ "$ref": "#/definitions/Template" })
+        );
+    }
+
+    #[test]
+    fn schema_properties_match_the_config_structs() {
+        let schema = serde_json::to_value(TemplateConfig::schema()).unwrap();
+        let config = TemplateConfig::parse(
+            include_str!("../templates/conventional.yml"),
+            ConfigFormat::Yaml,
+        )
+        .unwrap();
+        let template = config.commit.templates.values().next().unwrap();
+
+        let keys = |value: serde_json::Value| {
+            let mut keys: Vec<String> = value.as_object().unwrap().keys().cloned().collect();
+            keys.sort();
+            keys
+        };
+
+        assert_eq!(
+            keys(schema["properties"].clone()),
+            keys(serde_json::to_value(&config).unwrap())
+        );
+        assert_eq!(
+            keys(schema["definitions"]["Template"]["properties"].clone()),
+            keys(serde_json::to_value(template).unwrap())
+        );
+    }
+
+    #[test]
+    fn config_format_is_picked_from_the_file_extension() {
+        for (path, format) in [
+            (".inkan.yml", ConfigFormat::Yaml),
+            (".inkan.yaml", ConfigFormat::Yaml),
+            ("config", ConfigFormat::Yaml),
+            (".inkan.toml", ConfigFormat::Toml),
+            ("/path/to/config.json", ConfigFormat::Json),
+        ] {
+            assert_eq!(ConfigFormat::from_path(Path::new(path)), format, "{path}");
+        }
+    }
+
+    #[test]
+    fn toml_and_json_configs_are_parsed() {
+        let configs = [
+            (
+                ConfigFormat::Toml,
+                r#"
+version = 2
+
+[commit.templates.fix]
+description = "Fix"
+content = "fix: {message}"
+footer = false
+"#,
+            ),
+            (
+                ConfigFormat::Json,
+                r#"{
+  "version": 2,
+  "commit": {
+    "templates": {
+      "fix": { "description": "Fix", "content": "fix: {message}", "footer": false }
+    }
+  }
+}"#,
+            ),
+        ];
+
+        for (format, contents) in configs {
+            let config = TemplateConfig::parse(contents, format).unwrap();
+            let template = config.get_template_config("fix").unwrap();
+
+            assert_eq!(template.content, "fix: {message}", "{format}");
+            assert!(!template.footer, "{format}");
+            assert!(template.header, "{format}");
+        }
+    }
+
+    #[test]
+    fn unknown_keys_are_rejected_in_toml_and_json_configs() {
+        let configs = [
+            (
+                ConfigFormat::Toml,
+                "version = 2\n[commit]\nname = \"x\"\n[commit.templates]\n",
+            ),
+            (
+                ConfigFormat::Json,
+                r#"{"version": 2, "commit": {"name": "x", "templates": {}}}"#,
+            ),
+        ];
+
+        for (format, contents) in configs {
+            let error = TemplateConfig::parse(contents, format).unwrap_err();
+
+            assert_eq!(
+                error,
+                ConfigError::UnknownKeys {
+                    keys: vec!["commit.name".into()]
+                },
+                "{format}"
+            );
+        }
+    }
+
+    #[test]
+    fn invalid_configs_report_the_line_and_column() {
+        let configs = [
+            (ConfigFormat::Yaml, "version: 2\ncommit:\n  templates: [\n"),
+            (ConfigFormat::Toml, "version = 2\n\n[commit\n"),
+            (ConfigFormat::Json, "{\n  \"version\": 2,\n  \"commit\": }"),
+        ];
+
+        let position = Regex::new(r"line \d+ column \d+").unwrap();
+
+        for (format, contents) in configs {
+            let error = TemplateConfig::parse(contents, format).unwrap_err();
+
+            let ConfigError::Invalid { message } = error else {
+                panic!("Expected invalid {format} error");
+            };
+
+            assert!(message.starts_with(&format.to_string()), "{message}");
+            assert!(position.is_match(&message), "{message}");
+        }
+    }
+
+    #[test]
+    fn toml_error_positions_are_one_based() {
+        let error = ConfigFormat::Toml
+            .deserialize::<TemplateConfig>("version = 2\ncommit = 1\n")
+            .unwrap_err();
+
+        assert!(error.to_string().contains("at line 2 column"), "{error}");
+    }
+
+    #[test]
+    fn templates_and_branch_are_deep_merged_across_layers() {
+        let layers = [
+            layer(
+                "conventional",
+                r#"
+version: 2
+variables: [component]
+branch:
+  content: "{ticket_num}-{branch_name}"
+commit:
+  footer: "\n{link}"
+  templates:
+    fix:
+      description: Fix
+      content: "fix: {message}"
+    feat:
+      description: Feature
+      content: "feat: {message}"
+"#,
+            ),
+            layer(
+                "local",
+                r#"
+version: 2
+extends: conventional
+commit:
+  templates:
+    fix:
+      description: Bug fix
+    docs:
+      description: Docs
+      content: "docs: {message}"
+"#,
+            ),
+        ];
+
+        let config = TemplateConfig::merge(&layers).unwrap();
+
+        let fix = config.get_template_config("fix").unwrap();
+        assert_eq!(fix.description, "Bug fix");
+        assert_eq!(fix.content, "fix: {message}");
+        assert_eq!(
+            config.get_template_config("feat").unwrap().description,
+            "Feature"
+        );
+        assert_eq!(
+            config.get_template_config("docs").unwrap().content,
+            "docs: {message}"
+        );
+        assert_eq!(config.branch.unwrap().content, "{ticket_num}-{branch_name}");
+        assert_eq!(config.commit.footer, Some("\n{link}".into()));
+        assert_eq!(config.variables, vec!["component"]);
+        assert_eq!(config.extends, Some("conventional".into()));
+    }
+
+    #[test]
+    fn lists_are_replaced_rather_than_merged() {
+        let layers = [
+            layer(
+                "default",
+                "version: 2\nvariables: [a, b]\ncommit:\n  templates:\n    fix:\n      description: Fix\n      content: a\n      prompts:\n        - name: scope\n        - name: ticket_num\n",
+            ),
+            layer(
+                "local",
+                "version: 2\nvariables: [c]\ncommit:\n  templates:\n    fix:\n      prompts:\n        - name: link\n",
+            ),
+        ];
+
+        let config = TemplateConfig::merge(&layers).unwrap();
+
+        let prompts = &config.get_template_config("fix").unwrap().prompts;
+        assert_eq!(config.variables, vec!["c"]);
+        assert_eq!(prompts.len(), 1);
+        assert_eq!(prompts[0].name, "link");
+    }
+
+    #[test]
+    fn unknown_keys_are_only_rejected_for_layers_at_version_2() {
+        let v1 = layer(
+            "team",
+            "version: 1\ncommit:\n  name: team\n  templates:\n    fix:\n      description: Fix\n      content: a\n",
+        );
+
+        let config = TemplateConfig::merge(&[
+            v1.clone(),
+            layer(
+                "local",
+                "version: 2\nextends: team\ncommit:\n  templates: {}\n",
+            ),
+        ])
+        .unwrap();
+        assert_eq!(config.version, 2);
+
+        let error = TemplateConfig::merge(&[
+            v1,
+            layer(
+                "local",
+                "version: 2\ncommit:\n  templates:\n    fix:\n      descripton: Fix\n",
+            ),
+        ])
+        .unwrap_err();
+
+        assert_eq!(
+            error,
+            ConfigError::UnknownKeys {
+                keys: vec!["commit.templates.fix.descripton".into()]
+            }
+        );
+    }
+
+    #[test]
+    fn single_layer_is_parsed_as_is() {
+        let layers = [layer("local", "version: 2\ncommit:\n  templates: {}\n")];
+
+        let config = TemplateConfig::merge(&layers).unwrap();
+
+        assert_eq!(config.extends, None);
+        assert!(config.commit.templates.is_empty());
+        assert_eq!(layers[0].key, ConfigKey::Local);
+    }
+
+    #[test]
+    fn sources_are_the_layers_declaring_the_key_path_in_merge_order() {
+        let layers = [
+            layer(
+                "conventional",
+                "version: 2\nbranch:\n  content: a\ncommit:\n  templates:\n    fix:\n      description: Fix\n      content: a\n    feat:\n      description: Feat\n      content: b\n",
+            ),
+            layer(
+                "local",
+                "version: 2\nbranch: null\ncommit:\n  templates:\n    fix:\n      description: Bug\n",
+            ),
+        ];
+
+        let keys = |path: &[&str]| {
+            TemplateConfig::sources(&layers, path)
+                .unwrap()
+                .into_iter()
+                .map(|layer| layer.key.to_string())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            keys(&["commit", "templates", "fix"]),
+            vec!["conventional", "local"]
+        );
+        assert_eq!(keys(&["commit", "templates", "feat"]), vec!["conventional"]);
+        assert_eq!(keys(&["branch"]), vec!["conventional"]);
+        assert!(keys(&["commit", "templates", "docs"]).is_empty());
+    }
+
+    #[test]
+    fn templates_keep_the_order_declared_in_every_format() {
+        let configs = [
+            (
+                ConfigFormat::Yaml,
+                "version: 2\ncommit:\n  templates:\n    wip: {description: a, content: a}\n    fix: {description: a, content: a}\n    chore: {description: a, content: a}\n",
+            ),
+            (
+                ConfigFormat::Toml,
+                "version = 2\n[commit.templates.wip]\ndescription = \"a\"\ncontent = \"a\"\n[commit.templates.fix]\ndescription = \"a\"\ncontent = \"a\"\n[commit.templates.chore]\ndescription = \"a\"\ncontent = \"a\"\n",
+            ),
+            (
+                ConfigFormat::Json,
+                r#"{"version": 2, "commit": {"templates": {"wip": {"description": "a", "content": "a"}, "fix": {"description": "a", "content": "a"}, "chore": {"description": "a", "content": "a"}}}}"#,
+            ),
+        ];
+
+        for (format, contents) in configs {
+            let config = TemplateConfig::parse(contents, format).unwrap();
+
+            let names = config.commit.templates.keys().collect::<Vec<_>>();
+            assert_eq!(names, vec!["wip", "fix", "chore"], "{format}");
+        }
+    }
+
+    #[test]
+    fn ordered_templates_are_grouped_and_sorted_by_order() {
+        let config = TemplateConfig::parse(
+            r#"
+version: 2
+commit:
+  templates:
+    wip: { description: a, content: a }
+    feat: { description: a, content: a, group: Features }
+    fix: { description: a, content: a, group: Fixes, order: 2 }
+    hotfix: { description: a, content: a, group: Fixes, order: 1 }
+    refactor: { description: a, content: a, group: Features }
+"#,
+            ConfigFormat::Yaml,
+        )
+        .unwrap();
+
+        let names = config
+            .commit
+            .ordered_templates()
+            .into_iter()
+            .map(|(name, _)| name.as_str())
+            .collect::<Vec<_>>();
+
+        assert_eq!(names, vec!["hotfix", "fix", "wip", "feat", "refactor"]);
+    }
+
+    #[test]
+    fn merged_layers_keep_the_extended_template_order() {
+        let layers = [
+            layer(
+                "conventional",
+                "version: 2\ncommit:\n  templates:\n    wip: {description: a, content: a}\n    fix: {description: a, content: a}\n",
+            ),
+            layer(
+                "local",
+                "version: 2\ncommit:\n  templates:\n    docs: {description: a, content: a}\n    fix: {description: b}\n",
+            ),
+        ];
+
+        let config = TemplateConfig::merge(&layers).unwrap();
+
+        let names = config.commit.templates.keys().collect::<Vec<_>>();
+        assert_eq!(names, vec!["wip", "fix", "docs"]);
+    }
+
+    #[test]
+    fn templates_are_found_by_alias() {
+        let config =
+            TemplateConfig::parse(include_str!("../templates/default.yml"), ConfigFormat::Yaml)
+                .unwrap();
+
+        for name in ["bug", "fix", "bugfix"] {
+            let template = config.get_template_config(name).unwrap();
+
+            assert_eq!(
+                template.description,
+                "Fix that resolves an unintended issue"
+            );
+        }
+    }
+
+    #[test]
+    fn unknown_template_error_lists_the_valid_names_and_aliases() {
+        let config = TemplateConfig::parse(
+            "version: 2\ncommit:\n  templates:\n    bug: {description: a, content: a, aliases: [fix]}\n    feat: {description: a, content: a}\n",
+            ConfigFormat::Yaml,
+        )
+        .unwrap();
+
+        let error = config.get_template_config("feature").unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "Invalid command \"feature\" found expected one of [bug, fix, feat]"
+        );
+    }
+
+    #[test]
+    fn alias_collisions_error_when_loaded() {
+        let contents = "version: 2\ncommit:\n  templates:\n    bug: {description: a, content: a, aliases: [fix, feat]}\n    fix: {description: a, content: a}\n    feat: {description: a, content: a, aliases: [feat]}\n";
+
+        let error = TemplateConfig::parse(contents, ConfigFormat::Yaml).unwrap_err();
+
+        assert_eq!(
+            error,
+            ConfigError::AliasCollision {
+                names: vec!["feat".into(), "fix".into()]
+            }
+        );
+    }
+
+    #[test]
+    fn built_in_variables_declared_as_config_variables_error_when_loaded() {
+        let contents =
+            "version: 2\nvariables: [component, scope, branch_name]\ncommit:\n  templates: {}\n";
+
+        let error = TemplateConfig::parse(contents, ConfigFormat::Yaml).unwrap_err();
+
+        assert_eq!(
+            error,
+            ConfigError::ReservedVariables {
+                names: vec!["scope".into(), "branch_name".into()]
+            }
+        );
+    }
+
+    #[test]
+    fn alias_collisions_across_merged_layers_error() {
+        let layers = [
+            layer(
+                "default",
+                "version: 2\ncommit:\n  templates:\n    bug: {description: a, content: a, aliases: [fix]}\n",
+            ),
+            layer(
+                "local",
+                "version: 2\ncommit:\n  templates:\n    fix: {description: a, content: a}\n",
+            ),
+        ];
+
+        let error = TemplateConfig::merge(&layers).unwrap_err();
+
+        assert_eq!(
+            error,
+            ConfigError::AliasCollision {
+                names: vec!["fix".into()]
+            }
+        );
+    }
+
+    #[test]
+    fn default_template_is_picked_from_the_first_matching_branch_pattern() {
+        let config = TemplateConfig::parse(
+            r#"
+version: 2
+commit:
+  defaults:
+    - branch: ^hotfix/
+      template: bug
+    - branch: ^(docs|readme)/
+      template: docs
+    - branch: docs
+      template: chore
+  templates: {}
+"#,
+            ConfigFormat::Yaml,
+        )
+        .unwrap();
+
+        for (branch, expected) in [
+            ("hotfix/login", Some("bug")),
+            ("docs/readme", Some("docs")),
+            ("fix-docs", Some("chore")),
+            ("feature/hotfix/login", None),
+        ] {
+            let actual = config.commit.default_template(branch).unwrap();
+
+            assert_eq!(actual.as_deref(), expected, "{branch}");
+        }
+    }
+
+    #[test]
+    fn default_template_with_an_invalid_branch_pattern_errors() {
+        let config = TemplateConfig::parse(
+            "version: 2\ncommit:\n  defaults:\n    - branch: \"(\"\n      template: bug\n  templates: {}\n",
+            ConfigFormat::Yaml,
+        )
+        .unwrap();
+
+        let error = config.commit.default_template("main").unwrap_err();
+
+        assert!(matches!(error, UserInputError::Validation { name, .. } if name == "defaults"));
+    }
 }
@@ -0,0 +1,101 @@
+mod fakers;
+
+use inkan::domain::{
+    adapters::Store,
+    commands::branches::{handler, BranchContext},
+    models::Branch,
+};
+
+use crate::fakers::{fake_config, fake_context, GitCommandMock};
+
+#[test]
+fn branches_lists_stored_branches_for_the_repo() -> anyhow::Result<()> {
+    // Arrange
+    let git_commands = GitCommandMock {
+        repo: Ok("inkan".into()),
+        branch_name: Ok("feature/login".into()),
+        branches: || Ok(vec!["main".into(), "feature/login".into()]),
+        ..GitCommandMock::fake()
+    };
+
+    let context = fake_context(git_commands, fake_config())?;
+
+    let deleted = Branch::new("fix/typo", "inkan", Some("ABC-1".into()), None, None);
+    let current = Branch::new(
+        "feature/login",
+        "inkan",
+        Some("ABC-2".into()),
+        None,
+        Some("auth".into()),
+    );
+    let other_repo = Branch::new("feature/login", "other", None, None, None);
+
+    for branch in [&deleted, &current, &other_repo] {
+        context.store.persist_branch(branch)?;
+    }
+
+    // Act
+    let branches = handler(&context.git, &context.store)?;
+
+    // Assert
+    assert_eq!(
+        branches,
+        vec![
+            BranchContext {
+                name: "feature/login".into(),
+                exists: true,
+                current: true,
+                branch: current,
+            },
+            BranchContext {
+                name: "fix/typo".into(),
+                exists: false,
+                current: false,
+                branch: deleted,
+            },
+        ]
+    );
+
+    context.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn branches_excludes_repos_prefixed_by_the_repo_name() -> anyhow::Result<()> {
+    // Arrange
+    let git_commands = GitCommandMock {
+        repo: Ok("inkan".into()),
+        branch_name: Ok("main".into()),
+        branches: || Ok(vec!["main".into()]),
+        ..GitCommandMock::fake()
+    };
+
+    let context = fake_context(git_commands, fake_config())?;
+
+    let main = Branch::new("main", "inkan", None, None, None);
+    // Stored as `inkan-cli-main` which also starts with `inkan-`.
+    let prefixed_repo = Branch::new("main", "inkan-cli", None, None, None);
+
+    for branch in [&main, &prefixed_repo] {
+        context.store.persist_branch(branch)?;
+    }
+
+    // Act
+    let branches = handler(&context.git, &context.store)?;
+
+    // Assert
+    assert_eq!(
+        branches,
+        vec![BranchContext {
+            name: "main".into(),
+            exists: true,
+            current: true,
+            branch: main,
+        }]
+    );
+
+    context.close()?;
+
+    Ok(())
+}
@@ -1,13 +1,15 @@
 mod fakers;
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+
+use indexmap::IndexMap;
 
 use fake::{Fake, Faker};
 use inkan::{
     domain::{
         adapters::{CheckoutStatus, Store},
         commands::checkout::{handler, Checkout},
-        errors::{GitError, PersistError},
+        errors::{Errors, GitError, PersistError, TemplateError},
         models::Branch,
     },
     template_config::{BranchConfig, CommitConfig, TemplateConfig},
@@ -83,6 +85,10 @@ fn checkout_uses_branch_template() {
     let template = TemplateConfig {
         branch: Some(BranchConfig {
             content: "{branch_name}-{scope}-{ticket_num}".into(),
+            separator: "-".into(),
+            max_length: None,
+            types: vec![],
+            base: None,
         }),
         ..fake_template_config()
     };
@@ -107,13 +113,317 @@ fn checkout_uses_branch_template() {
         link: command.link,
         scope: command.scope,
         created: branch.created,
-        data: None,
+        variables: BTreeMap::new(),
+        repo: Some(repo.clone()),
     };
 
     assert_eq!(branch, expected);
     context.close().unwrap();
 }
 
+#[test]
+fn checkout_applies_branch_template_filters() {
+    let repo = Faker.fake::<String>();
+
+    let command = Checkout {
+        name: "Add Login Page".into(),
+        ticket: Some("abc-12".into()),
+        ..fake_checkout_args()
+    };
+
+    let context = fake_context(
+        GitCommandMock {
+            repo: Ok(repo.clone()),
+            ..GitCommandMock::fake()
+        },
+        fake_config(),
+    )
+    .unwrap();
+
+    let template = TemplateConfig {
+        branch: Some(BranchConfig {
+            content: "{ticket_num|upper}-{branch_name|slug}".into(),
+            separator: "-".into(),
+            max_length: None,
+            types: vec![],
+            base: None,
+        }),
+        ..fake_template_config()
+    };
+
+    // Act
+    let (branch, branch_name, _) =
+        handler(&context.git, &context.store, template, command).unwrap();
+
+    // Assert
+    assert_eq!(branch.name, format!("{}-ABC-12-add-login-page", repo));
+    assert!(branch_name.changes.is_empty());
+    context.close().unwrap();
+}
+
+#[test]
+fn checkout_sanitizes_branch_name_into_a_valid_git_ref() {
+    let repo = Faker.fake::<String>();
+
+    let command = Checkout {
+        name: "Fix: login.. page~2".into(),
+        ticket: Some("ABC-12".into()),
+        ..fake_checkout_args()
+    };
+
+    let context = fake_context(
+        GitCommandMock {
+            repo: Ok(repo.clone()),
+            checkout_res: |name, _| {
+                assert_eq!(name, "ABC-12/Fix_login");
+                Ok(())
+            },
+            ..GitCommandMock::fake()
+        },
+        fake_config(),
+    )
+    .unwrap();
+
+    let template = TemplateConfig {
+        branch: Some(BranchConfig {
+            content: "{ticket_num}/{branch_name}".into(),
+            separator: "_".into(),
+            max_length: Some(18),
+            types: vec![],
+            base: None,
+        }),
+        ..fake_template_config()
+    };
+
+    // Act
+    let (branch, branch_name, _) =
+        handler(&context.git, &context.store, template, command).unwrap();
+
+    // Assert
+    assert_eq!(branch.name, format!("{}-ABC-12/Fix_login", repo));
+    assert_eq!(branch_name.name, "ABC-12/Fix_login");
+    assert_eq!(
+        branch_name.changes,
+        vec![
+            "replaced ':', ' ', '~' with \"_\"",
+            "replaced \"..\" with \".\"",
+            "truncated to 18 characters",
+            "removed trailing '.'",
+        ]
+    );
+    context.close().unwrap();
+}
+
+#[test]
+fn checkout_uses_branch_type_and_title() {
+    let repo = Faker.fake::<String>();
+
+    let command = Checkout {
+        ticket: Some("ABC-12".into()),
+        branch_type: Some("feature".into()),
+        title: Some("Add Login".into()),
+        ..fake_checkout_args()
+    };
+
+    let context = fake_context(
+        GitCommandMock {
+            repo: Ok(repo.clone()),
+            ..GitCommandMock::fake()
+        },
+        fake_config(),
+    )
+    .unwrap();
+
+    let template = TemplateConfig {
+        branch: Some(BranchConfig {
+            content: "{type}/{ticket_num}-{title|slug}".into(),
+            separator: "-".into(),
+            max_length: None,
+            types: vec!["feature".into(), "hotfix".into()],
+            base: None,
+        }),
+        ..fake_template_config()
+    };
+
+    // Act
+    let (_, branch_name, _) = handler(&context.git, &context.store, template, command).unwrap();
+
+    // Assert
+    assert_eq!(branch_name.name, "feature/ABC-12-add-login");
+    context.close().unwrap();
+}
+
+#[test]
+fn checkout_creates_new_branch_from_ref() {
+    let command = Checkout {
+        from: Some("origin/main".into()),
+        ..fake_checkout_args()
+    };
+
+    let context = fake_context(
+        GitCommandMock {
+            checkout_res: |_, status| {
+                assert_eq!(
+                    status,
+                    CheckoutStatus::New {
+                        from: Some("origin/main".into())
+                    }
+                );
+                Ok(())
+            },
+            ..GitCommandMock::fake()
+        },
+        fake_config(),
+    )
+    .unwrap();
+
+    // Act
+    handler(
+        &context.git,
+        &context.store,
+        fake_template_config(),
+        command,
+    )
+    .unwrap();
+
+    context.close().unwrap();
+}
+
+#[test]
+fn checkout_from_ref_with_branch_already_exists_checks_out_existing_branch() {
+    let command = Checkout {
+        name: "feature/login".into(),
+        from: Some("origin/main".into()),
+        ..fake_checkout_args()
+    };
+
+    let context = fake_context(
+        GitCommandMock {
+            branches: || Ok(vec!["main".into(), "feature/login".into()]),
+            checkout_res: |_, status| {
+                assert_eq!(status, CheckoutStatus::Existing);
+                Ok(())
+            },
+            ..GitCommandMock::fake()
+        },
+        fake_config(),
+    )
+    .unwrap();
+
+    // Act
+    let (_, _, status) = handler(
+        &context.git,
+        &context.store,
+        fake_template_config(),
+        command,
+    )
+    .unwrap();
+
+    // Assert
+    assert_eq!(status, CheckoutStatus::Existing);
+    context.close().unwrap();
+}
+
+#[test]
+fn checkout_from_invalid_ref_errors() {
+    let command = Checkout {
+        name: "feature/login".into(),
+        from: Some("orgin/main".into()),
+        ..fake_checkout_args()
+    };
+
+    let context = fake_context(
+        GitCommandMock {
+            branches: || Ok(vec!["main".into()]),
+            checkout_res: |name, status| match status {
+                CheckoutStatus::New { .. } => Err(GitError::Checkout { name: name.into() }),
+                CheckoutStatus::Existing => panic!("existing branch should not be checked out"),
+            },
+            ..GitCommandMock::fake()
+        },
+        fake_config(),
+    )
+    .unwrap();
+
+    // Act
+    let error = handler(
+        &context.git,
+        &context.store,
+        fake_template_config(),
+        command,
+    )
+    .unwrap_err();
+
+    // Assert
+    assert_eq!(
+        error.to_string(),
+        "Failed to create branch \"feature/login\" from \"orgin/main\" expected a valid git ref"
+    );
+    context.close().unwrap();
+}
+
+#[test]
+fn checkout_from_valid_ref_that_fails_returns_the_git_error() {
+    let command = Checkout {
+        name: "feature/login".into(),
+        from: Some("origin/main".into()),
+        ..fake_checkout_args()
+    };
+
+    let context = fake_context(
+        GitCommandMock {
+            branches: || Ok(vec!["main".into()]),
+            short_sha: |_| Ok(Some("a1b2c3d".into())),
+            checkout_res: |name, _| Err(GitError::Checkout { name: name.into() }),
+            ..GitCommandMock::fake()
+        },
+        fake_config(),
+    )
+    .unwrap();
+
+    // Act
+    let error = handler(
+        &context.git,
+        &context.store,
+        fake_template_config(),
+        command,
+    )
+    .unwrap_err();
+
+    // Assert
+    assert!(matches!(
+        error,
+        Errors::Git(GitError::Checkout { ref name }) if name == "feature/login"
+    ));
+    context.close().unwrap();
+}
+
+#[test]
+fn checkout_with_unknown_branch_template_filter_errors() {
+    let context = fake_context(GitCommandMock::fake(), fake_config()).unwrap();
+
+    let template = TemplateConfig {
+        branch: Some(BranchConfig {
+            content: "{branch_name|shout}".into(),
+            separator: "-".into(),
+            max_length: None,
+            types: vec![],
+            base: None,
+        }),
+        ..fake_template_config()
+    };
+
+    // Act
+    let error = handler(&context.git, &context.store, template, fake_checkout_args()).unwrap_err();
+
+    // Assert
+    assert!(matches!(
+        error,
+        Errors::Template(TemplateError::UnknownFilter { ref name }) if name == "shout"
+    ));
+    context.close().unwrap();
+}
+
 #[test]
 fn checkout_with_branch_already_exists_does_not_error() -> anyhow::Result<()> {
     // Arrange
@@ -125,7 +435,7 @@ fn checkout_with_branch_already_exists_does_not_error() -> anyhow::Result<()> {
         repo: Ok(repo.clone()),
         branch_name: Ok(command.name.clone()),
         checkout_res: |_, status| {
-            assert_eq!(status, CheckoutStatus::New);
+            assert_eq!(status, CheckoutStatus::New { from: None });
             Ok(())
         },
         ..GitCommandMock::fake()
@@ -155,7 +465,8 @@ fn checkout_with_branch_already_exists_does_not_error() -> anyhow::Result<()> {
         link: command.link,
         scope: command.scope,
         created: branch.created,
-        data: None,
+        variables: BTreeMap::new(),
+        repo: Some(repo.clone()),
     };
 
     assert_eq!(branch, expected);
@@ -174,6 +485,10 @@ fn checkout_on_fail_to_checkout_branch_nothing_is_persisted() {
     let git_commands = GitCommandMock {
         repo: Ok(repo.clone()),
         branch_name: Ok(command.name.clone()),
+        config_value: |_| panic!("config value should not be needed"),
+        short_sha: |_| panic!("short sha should not be needed"),
+        branches: || panic!("branches should not be needed"),
+        is_dirty: || panic!("working tree status should not be needed"),
         checkout_res: |_, _| {
             Err(GitError::Validation {
                 message: "failed to create or checkout existing branch!".into(),
@@ -247,8 +562,9 @@ fn checkout_success_without_ticket_uses_branch_name() -> anyhow::Result<()> {
         ticket: command.name,
         scope: command.scope,
         link: command.link,
-        data: None,
+        variables: BTreeMap::new(),
         created: branch.created,
+        repo: Some(repo),
     };
 
     assert_eq!(branch, expected);
@@ -264,15 +580,24 @@ pub fn fake_checkout_args() -> Checkout {
         ticket: Some(Faker.fake()),
         link: Some(Faker.fake()),
         scope: Some(Faker.fake()),
+        branch_type: None,
+        title: None,
+        from: None,
     }
 }
 
 pub fn fake_template_config() -> TemplateConfig {
     TemplateConfig {
         version: 1,
+        extends: None,
+        variables: vec![],
         branch: None,
         commit: CommitConfig {
-            templates: HashMap::new(),
+            header: None,
+            footer: None,
+            partials: HashMap::new(),
+            defaults: vec![],
+            templates: IndexMap::new(),
         },
     }
 }
@@ -1,5 +1,7 @@
 mod fakers;
 
+use std::collections::BTreeMap;
+
 use crate::fakers::{fake_config, fake_context, GitCommandMock};
 use fake::{Fake, Faker};
 use inkan::{
@@ -76,6 +78,7 @@ fn commit_message_with_no_args_or_stored_branch_defaults_correctly() -> anyhow::
         scope: None,
         link: None,
         template: template_config,
+        variables: BTreeMap::new(),
     };
 
     // Act
@@ -101,17 +104,18 @@ fn commit_message_with_no_commit_args_defaults_to_stored_branch_values() -> anyh
         ticket: None,
         scope: None,
         link: None,
+        variables: BTreeMap::new(),
     };
 
     let context = fake_context(GitCommandMock::fake(), fake_config())?;
 
-    let branch_name = Some(context.git.branch_name()?);
+    let branch_name = context.git.branch_name()?;
     let repo_name = Some(context.git.repository_name()?);
     let ticket = None;
     let branch = Branch {
         link: Some(Faker.fake()),
         scope: Some(Faker.fake()),
-        ..fake_branch(branch_name.clone(), repo_name, ticket)?
+        ..fake_branch(Some(branch_name.clone()), repo_name, ticket)?
     };
 
     setup_db(&context.store, Some(&branch))?;
@@ -123,7 +127,7 @@ fn commit_message_with_no_commit_args_defaults_to_stored_branch_values() -> anyh
     // Assert
     let expected = format!(
         "[{}] message: '{}', scope: '{}', link: '{}'",
-        branch_name.unwrap(),
+        branch_name,
         args.message.unwrap(),
         branch.scope.unwrap(),
         branch.link.unwrap()
@@ -136,9 +140,45 @@ fn commit_message_with_no_commit_args_defaults_to_stored_branch_values() -> anyh
     Ok(())
 }
 
+#[test]
+fn commit_message_built_ins_are_resolved_from_git() -> anyhow::Result<()> {
+    // Arrange
+    let git_mock = GitCommandMock {
+        repo: Ok("inkan".into()),
+        branch_name: Ok("release/1.0".into()),
+        config_value: |key| match key {
+            "user.name" => Ok(Some("Jane Doe".into())),
+            _ => Ok(None),
+        },
+        short_sha: |_| Ok(Some("a1b2c3d".into())),
+        ..GitCommandMock::fake()
+    };
+
+    let context = fake_context(git_mock, fake_config())?;
+
+    let args = Commit {
+        message: Some("msg".into()),
+        template: Template {
+            content: "{message}\n\n{repo}@{branch_name} ({short_sha_parent})\n{author_name} ({author_email})".into(),
+            ..fake_template()
+        },
+        ..fake_commit_args()
+    };
+
+    // Act
+    let contents = handler(&context.git, None, args).expect("Error performing 'commit' action");
+
+    // Assert
+    assert_eq!("msg\n\ninkan@release/1.0 (a1b2c3d)\nJane Doe", contents);
+
+    context.close()?;
+
+    Ok(())
+}
+
 fn setup_db(store: &Sqlite, branch: Option<&Branch>) -> anyhow::Result<()> {
     if let Some(branch) = branch {
-        store.persist_branch(branch.into())?;
+        store.persist_branch(branch)?;
     }
 
     Ok(())
@@ -153,6 +193,7 @@ fn fake_commit_args() -> Commit {
         message: Faker.fake(),
         scope: Faker.fake(),
         link: Faker.fake(),
+        variables: BTreeMap::new(),
     }
 }
 
@@ -160,6 +201,12 @@ fn fake_template() -> Template {
     Template {
         description: Faker.fake(),
         content: "[{ticket_num}] message: '{message}', scope: '{scope}', link: '{link}'".into(),
+        aliases: vec![],
+        header: true,
+        footer: true,
+        group: None,
+        order: None,
+        prompts: vec![],
     }
 }
 
@@ -0,0 +1,219 @@
+mod fakers;
+
+use fake::{Fake, Faker};
+use inkan::{
+    domain::{
+        adapters::Store,
+        errors::Errors,
+        models::{path::AbsolutePath, ConfigKey, Template, TemplateStatus},
+    },
+    template_config::TemplateConfig,
+};
+
+use crate::fakers::{fake_config, fake_context, GitCommandMock};
+
+fn config_file(extension: &str, contents: &str) -> AbsolutePath {
+    let path = std::env::temp_dir().join(format!("{}.{extension}", Faker.fake::<String>()));
+    std::fs::write(&path, contents).unwrap();
+
+    path.try_into().unwrap()
+}
+
+fn user_config(key: &str, contents: &str) -> Template {
+    Template {
+        key: ConfigKey::User(key.into()),
+        path: config_file("yml", contents),
+        status: TemplateStatus::Disabled,
+    }
+}
+
+#[test]
+fn local_config_is_merged_on_top_of_the_configs_it_extends() -> anyhow::Result<()> {
+    // Arrange
+    let context = fake_context(GitCommandMock::fake(), fake_config())?;
+
+    let team = user_config(
+        "team",
+        r#"
+version: 2
+commit:
+  templates:
+    fix:
+      description: Fix
+      content: "fix: {message}"
+    feat:
+      description: Feature
+      content: "feat: {message}"
+"#,
+    );
+    context.store.persist_template(&team)?;
+
+    let local = Template {
+        key: ConfigKey::Local,
+        path: config_file(
+            "toml",
+            r#"
+version = 2
+extends = "team"
+
+[commit.templates.fix]
+description = "Bug fix"
+
+[commit.templates.docs]
+description = "Docs"
+content = "docs: {message}"
+"#,
+        ),
+        status: TemplateStatus::Active,
+    };
+
+    // Act
+    let layers = TemplateConfig::layers(&local, &context.store)?;
+    let config = TemplateConfig::load(&local, &context.store)?;
+
+    // Assert
+    let keys: Vec<_> = layers.into_iter().map(|layer| layer.key).collect();
+    assert_eq!(keys, vec![team.key, ConfigKey::Local]);
+
+    let mut names: Vec<_> = config.commit.templates.keys().cloned().collect();
+    names.sort();
+    assert_eq!(names, vec!["docs", "feat", "fix"]);
+
+    let fix = config.get_template_config("fix")?;
+    assert_eq!(fix.description, "Bug fix");
+    assert_eq!(fix.content, "fix: {message}");
+
+    context.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn configs_extending_each_other_error() -> anyhow::Result<()> {
+    // Arrange
+    let context = fake_context(GitCommandMock::fake(), fake_config())?;
+
+    let a = user_config("a", "version: 2\nextends: b\ncommit:\n  templates: {}\n");
+    let b = user_config("b", "version: 2\nextends: a\ncommit:\n  templates: {}\n");
+    context.store.persist_template(&a)?;
+    context.store.persist_template(&b)?;
+
+    // Act
+    let error = TemplateConfig::load(&a, &context.store).unwrap_err();
+
+    // Assert
+    let Errors::Configuration { source, .. } = error else {
+        panic!("Expected configuration error");
+    };
+    assert_eq!(
+        source.to_string(),
+        "Config 'a' is extended more than once forming a cycle"
+    );
+
+    context.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn extending_an_unknown_config_errors() -> anyhow::Result<()> {
+    // Arrange
+    let context = fake_context(GitCommandMock::fake(), fake_config())?;
+
+    let local = user_config(
+        "local",
+        "version: 2\nextends: missing\ncommit:\n  templates: {}\n",
+    );
+
+    // Act
+    let error = TemplateConfig::load(&local, &context.store).unwrap_err();
+
+    // Assert
+    let Errors::Configuration { message, .. } = error else {
+        panic!("Expected configuration error");
+    };
+    assert!(
+        message.starts_with("Failed to find config 'missing'"),
+        "{message}"
+    );
+
+    context.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn nested_local_configs_are_merged_on_top_of_parent_local_configs() -> anyhow::Result<()> {
+    // Arrange
+    let context = fake_context(GitCommandMock::fake(), fake_config())?;
+
+    let repo_root = std::env::temp_dir().join(Faker.fake::<String>());
+    let package = repo_root.join("packages").join("api");
+    std::fs::create_dir_all(&package)?;
+    std::fs::create_dir(repo_root.join(".git"))?;
+
+    let root_config = repo_root.join(".inkan.yml");
+    std::fs::write(
+        &root_config,
+        "version: 2\ncommit:\n  templates:\n    fix:\n      description: Fix\n      content: \"fix: {message}\"\n",
+    )?;
+
+    let package_config = package.join(".inkan.yml");
+    std::fs::write(
+        &package_config,
+        r#"
+version: 2
+commit:
+  templates:
+    fix:
+      prompts:
+        - name: scope
+          choices: [api, db]
+    migration:
+      description: Database migration
+      content: "migration: {message}"
+"#,
+    )?;
+
+    let local = Template {
+        key: ConfigKey::Local,
+        path: package_config.clone().try_into()?,
+        status: TemplateStatus::Active,
+    };
+
+    // Act
+    let layers = TemplateConfig::layers(&local, &context.store)?;
+    let config = TemplateConfig::load(&local, &context.store)?;
+
+    // Assert
+    let paths: Vec<std::path::PathBuf> =
+        layers.into_iter().map(|layer| layer.path.into()).collect();
+    assert_eq!(paths, vec![root_config, package_config.clone()]);
+
+    let fix = config.get_template_config("fix")?;
+    assert_eq!(fix.content, "fix: {message}");
+    assert_eq!(fix.prompts[0].choices, vec!["api", "db"]);
+    assert!(config.get_template_config("migration").is_ok());
+
+    // An explicit extends replaces the parent local config.
+    let team = user_config(
+        "team",
+        "version: 2\ncommit:\n  templates:\n    chore:\n      description: Chore\n      content: a\n",
+    );
+    context.store.persist_template(&team)?;
+    std::fs::write(
+        &package_config,
+        "version: 2\nextends: team\ncommit:\n  templates: {}\n",
+    )?;
+
+    let keys: Vec<_> = TemplateConfig::layers(&local, &context.store)?
+        .into_iter()
+        .map(|layer| layer.key)
+        .collect();
+    assert_eq!(keys, vec![team.key, ConfigKey::Local]);
+
+    std::fs::remove_dir_all(repo_root)?;
+    context.close()?;
+
+    Ok(())
+}
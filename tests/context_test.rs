@@ -1,5 +1,7 @@
 mod fakers;
 
+use std::collections::BTreeMap;
+
 use fake::{Fake, Faker};
 use inkan::domain::{
     adapters::Store,
@@ -7,7 +9,7 @@ use inkan::domain::{
     models::Branch,
 };
 
-use crate::fakers::{fake_branch, fake_config, fake_context, GitCommandMock};
+use crate::fakers::{fake_config, fake_context, GitCommandMock};
 
 #[test]
 fn current_success() -> anyhow::Result<()> {
@@ -55,46 +57,101 @@ fn current_success() -> anyhow::Result<()> {
 #[test]
 fn context_with_optionals_none_does_not_overwrite_db() -> anyhow::Result<()> {
     // Arrange
-    let branch = Branch {
-        data: Some(Faker.fake()),
-        link: Some(Faker.fake()),
-        scope: Some(Faker.fake()),
-        ..fake_branch()
-    };
-
     let repo = Faker.fake::<String>();
+    let branch_name = Faker.fake::<String>();
     let git_commands = GitCommandMock {
         repo: Ok(repo.clone()),
-        branch_name: Ok(branch.name.clone()),
+        branch_name: Ok(branch_name.clone()),
         ..GitCommandMock::fake()
     };
 
-    let context = fake_context(git_commands.clone(), fake_config())?;
+    let context = fake_context(git_commands, fake_config())?;
 
+    let branch = Branch {
+        variables: BTreeMap::from([("component".into(), "api".into())]),
+        ..Branch::new(
+            &branch_name,
+            &repo,
+            Some(Faker.fake()),
+            Some(Faker.fake()),
+            Some(Faker.fake()),
+        )
+    };
     context.store.persist_branch(&branch)?;
 
     let command = Context {
         ticket: None,
         scope: None,
         link: None,
+        variables: BTreeMap::from([("epic".into(), "EPIC-1".into())]),
     };
 
     // Act
-    handler(&context.git, &context.store, command.clone())?;
+    handler(&context.git, &context.store, command)?;
 
     // Assert
-    let updated_branch = context.store.get_branch(&branch.name, &repo)?;
-    let name = format!(
-        "{}-{}",
-        &git_commands.repo.unwrap(),
-        &git_commands.branch_name.unwrap()
-    );
+    let updated_branch = context.store.get_branch(&branch_name, &repo)?;
 
     let expected = Branch {
-        name,
-        ..updated_branch.clone()
+        variables: BTreeMap::from([
+            ("component".into(), "api".into()),
+            ("epic".into(), "EPIC-1".into()),
+        ]),
+        ..branch
     };
     assert_eq!(updated_branch, expected);
+
+    context.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn context_variables_are_merged_with_existing_branch_variables() -> anyhow::Result<()> {
+    // Arrange
+    let repo = Faker.fake::<String>();
+    let branch_name = Faker.fake::<String>();
+    let git_commands = GitCommandMock {
+        repo: Ok(repo.clone()),
+        branch_name: Ok(branch_name.clone()),
+        ..GitCommandMock::fake()
+    };
+
+    let context = fake_context(git_commands, fake_config())?;
+
+    let branch = Branch {
+        variables: BTreeMap::from([
+            ("component".into(), "api".into()),
+            ("reviewer".into(), "sam".into()),
+        ]),
+        ..Branch::new(&branch_name, &repo, None, None, None)
+    };
+    context.store.persist_branch(&branch)?;
+
+    let command = Context {
+        variables: BTreeMap::from([
+            ("epic".into(), "EPIC-1".into()),
+            ("reviewer".into(), "".into()),
+        ]),
+        ..fake_context_args()
+    };
+
+    // Act
+    handler(&context.git, &context.store, command)?;
+
+    // Assert
+    let updated_branch = context.store.get_branch(&branch_name, &repo)?;
+
+    assert_eq!(
+        updated_branch.variables,
+        BTreeMap::from([
+            ("component".into(), "api".into()),
+            ("epic".into(), "EPIC-1".into()),
+        ])
+    );
+
+    context.close()?;
+
     Ok(())
 }
 
@@ -103,5 +160,6 @@ fn fake_context_args() -> Context {
         ticket: Faker.fake(),
         scope: Faker.fake(),
         link: Faker.fake(),
+        variables: Faker.fake(),
     }
 }
@@ -37,14 +37,14 @@ pub fn fake_config() -> Template {
     }
 }
 
-pub fn fake_context<'a, C: Git>(git: C, config: Template) -> anyhow::Result<AppContext<C, Sqlite>> {
+pub fn fake_context<C: Git>(git: C, config: Template) -> anyhow::Result<AppContext<C, Sqlite>> {
     let mut connection = Connection::open_in_memory()?;
 
     db_migrations(
         &mut connection,
         MigrationContext {
             default_configs: None,
-            version: 4,
+            version: 5,
         },
     )?;
 
@@ -64,9 +64,10 @@ pub fn fake_branch() -> Branch {
         name: Faker.fake(),
         ticket: Faker.fake(),
         created: Utc::now(),
-        data: Faker.fake(),
+        variables: Faker.fake(),
         link: Faker.fake(),
         scope: Faker.fake(),
+        repo: Faker.fake(),
     }
 }
 
@@ -74,6 +75,10 @@ pub fn fake_branch() -> Branch {
 pub struct GitCommandMock {
     pub repo: Result<String, String>,
     pub branch_name: Result<String, String>,
+    pub config_value: fn(&str) -> Result<Option<String>, GitError>,
+    pub short_sha: fn(&str) -> Result<Option<String>, GitError>,
+    pub branches: fn() -> Result<Vec<String>, GitError>,
+    pub is_dirty: fn() -> Result<bool, GitError>,
     pub checkout_res: fn(&str, CheckoutStatus) -> Result<(), GitError>,
     pub commit_res: fn(&Path, CommitMsgStatus) -> Result<(), GitError>,
     pub template_file_path: fn() -> Result<PathBuf, GitError>,
@@ -84,6 +89,10 @@ impl GitCommandMock {
         GitCommandMock {
             repo: Ok(Faker.fake()),
             branch_name: Ok(Faker.fake()),
+            config_value: |_| Ok(None),
+            short_sha: |_| Ok(None),
+            branches: || Ok(vec![]),
+            is_dirty: || Ok(false),
             checkout_res: |_, _| Ok(()),
             commit_res: |_, _| Ok(()),
             template_file_path: || Ok(VALID_FILE_PATH.clone().into()),
@@ -106,6 +115,22 @@ impl Git for GitCommandMock {
             .map_err(|e| GitError::Validation { message: e.into() })
     }
 
+    fn config_value(&self, key: &str) -> Result<Option<String>, GitError> {
+        (self.config_value)(key)
+    }
+
+    fn short_sha(&self, revision: &str) -> Result<Option<String>, GitError> {
+        (self.short_sha)(revision)
+    }
+
+    fn branches(&self) -> Result<Vec<String>, GitError> {
+        (self.branches)()
+    }
+
+    fn is_dirty(&self) -> Result<bool, GitError> {
+        (self.is_dirty)()
+    }
+
     fn checkout(&self, name: &str, status: CheckoutStatus) -> Result<(), GitError> {
         (self.checkout_res)(name, status)
     }
@@ -0,0 +1,222 @@
+mod fakers;
+
+use chrono::{Duration, Utc};
+use inkan::{
+    adapters::{
+        migrations::{backfill_branch_repos, db_migrations, MigrationContext},
+        sqlite::Sqlite,
+    },
+    app_context::AppContext,
+    domain::{
+        adapters::Store,
+        commands::gc::{handler, Gc},
+        errors::{Errors, PersistError},
+        models::Branch,
+    },
+    entry::Interactive,
+};
+use rusqlite::Connection;
+
+use crate::fakers::{fake_config, fake_context, GitCommandMock};
+
+fn git_commands() -> GitCommandMock {
+    GitCommandMock {
+        repo: Ok("inkan".into()),
+        branches: || Ok(vec!["main".into(), "feature/login".into()]),
+        ..GitCommandMock::fake()
+    }
+}
+
+fn branch(name: &str, days_old: i64) -> Branch {
+    Branch {
+        created: Utc::now() - Duration::days(days_old),
+        ..Branch::new(name, "inkan", None, None, None)
+    }
+}
+
+#[test]
+fn gc_removes_branches_that_no_longer_exist() -> anyhow::Result<()> {
+    // Arrange
+    let context = fake_context(git_commands(), fake_config())?;
+
+    let (existing, deleted) = (branch("feature/login", 40), branch("fix/typo", 40));
+    let other_repo = Branch::new("fix/typo", "other", None, None, None);
+
+    for branch in [&existing, &deleted, &other_repo] {
+        context.store.persist_branch(branch)?;
+    }
+
+    let args = Gc {
+        dry_run: false,
+        older_than: None,
+    };
+
+    // Act
+    let removed = handler(&context.git, &context.store, args)?;
+
+    // Assert
+    assert_eq!(removed, vec![("fix/typo".into(), deleted)]);
+    assert!(matches!(
+        context.store.get_branch("fix/typo", "inkan"),
+        Err(PersistError::NotFound { .. })
+    ));
+    assert_eq!(
+        context.store.get_branch("feature/login", "inkan")?,
+        existing
+    );
+    assert_eq!(context.store.get_branch("fix/typo", "other")?, other_repo);
+
+    context.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn gc_keeps_branches_of_repos_prefixed_by_the_repo_name() -> anyhow::Result<()> {
+    // Arrange
+    let context = fake_context(git_commands(), fake_config())?;
+
+    // Stored as `inkan-cli-main` which also starts with `inkan-`.
+    let prefixed_repo = Branch {
+        created: Utc::now() - Duration::days(40),
+        ..Branch::new("main", "inkan-cli", None, None, None)
+    };
+    context.store.persist_branch(&prefixed_repo)?;
+
+    // Act
+    let removed = handler(
+        &context.git,
+        &context.store,
+        Gc {
+            dry_run: false,
+            older_than: None,
+        },
+    )?;
+
+    // Assert
+    assert!(removed.is_empty());
+    assert_eq!(
+        context.store.get_branch("main", "inkan-cli")?,
+        prefixed_repo
+    );
+
+    context.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn gc_removes_backfilled_branches_stored_without_a_repo() -> anyhow::Result<()> {
+    // Arrange
+    let mut connection = Connection::open_in_memory()?;
+    db_migrations(
+        &mut connection,
+        MigrationContext {
+            default_configs: None,
+            version: 5,
+        },
+    )?;
+
+    let deleted = Branch {
+        repo: None,
+        ..branch("fix/typo", 40)
+    };
+    connection.execute(
+        "INSERT INTO branch (name, ticket, created) VALUES (?1, ?2, ?3)",
+        (&deleted.name, &deleted.ticket, deleted.created.to_rfc3339()),
+    )?;
+
+    backfill_branch_repos(&connection, "inkan")?;
+
+    let context = AppContext {
+        store: Sqlite::new(connection),
+        config: fake_config(),
+        git: git_commands(),
+        interactive: Interactive::Disable,
+    };
+
+    // Act
+    let removed = handler(
+        &context.git,
+        &context.store,
+        Gc {
+            dry_run: false,
+            older_than: None,
+        },
+    )?;
+
+    // Assert
+    assert_eq!(
+        removed,
+        vec![(
+            "fix/typo".into(),
+            Branch {
+                repo: Some("inkan".into()),
+                ..deleted
+            }
+        )]
+    );
+    assert!(context.store.get_branches("inkan")?.is_empty());
+
+    context.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn gc_dry_run_and_age_threshold_keep_branches() -> anyhow::Result<()> {
+    // Arrange
+    let context = fake_context(git_commands(), fake_config())?;
+
+    let (old, recent) = (branch("fix/old", 40), branch("fix/recent", 2));
+    context.store.persist_branch(&old)?;
+    context.store.persist_branch(&recent)?;
+
+    // Act
+    let dry_run = handler(
+        &context.git,
+        &context.store,
+        Gc {
+            dry_run: true,
+            older_than: Some(30),
+        },
+    )?;
+
+    // Assert
+    assert_eq!(dry_run, vec![("fix/old".into(), old.clone())]);
+    assert_eq!(context.store.get_branches("inkan")?, vec![recent, old]);
+
+    context.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn gc_without_any_local_branches_errors() -> anyhow::Result<()> {
+    // Arrange
+    let git_commands = GitCommandMock {
+        branches: || Ok(vec![]),
+        ..git_commands()
+    };
+    let context = fake_context(git_commands, fake_config())?;
+    context.store.persist_branch(&branch("fix/typo", 40))?;
+
+    // Act
+    let error = handler(
+        &context.git,
+        &context.store,
+        Gc {
+            dry_run: false,
+            older_than: None,
+        },
+    )
+    .unwrap_err();
+
+    // Assert
+    assert!(matches!(error, Errors::ValidationError { .. }));
+    assert_eq!(context.store.get_branches("inkan")?.len(), 1);
+
+    context.close()?;
+
+    Ok(())
+}